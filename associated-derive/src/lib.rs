@@ -4,9 +4,10 @@
 //!
 //! Add `#[derive(Associated)]` to an enum definition. This is not compatible with structs or unions.
 //!
-//! When deriving `Associated` you must include a `#[associate(Type = associated_type)]` attribute beneath
+//! When deriving `Associated` you must include a `#[associated(Type = associated_type)]` attribute beneath
 //! the `#[derive(Associated)]` attribute, replacing `associated_type` with the type of the constants you
-//! want to associate with the enum variants.
+//! want to associate with the enum variants. `Type` defaults to `&'static str`, so for string constants a
+//! bare `#[associated]` is enough.
 //!
 //! For each and **every** variant of the enum you must include either a `#[assoc(expr)]` or
 //! `#[assoc_const(const_expr)]` attribute above or inline before the variant, with `expr` or `const_expr`
@@ -15,6 +16,7 @@
 //! ### Example
 //!
 //! ```rust
+//! # use associated::Associated;
 //! #[derive(Associated)]
 //! #[associated(Type = &'static str)]
 //! enum Phonetic {
@@ -24,12 +26,13 @@
 //!     // ...
 //! }
 //!
-//! Phonetic::Alpha.get_associated() // returns a static lifetime reference to "Alpha"
+//! Phonetic::Alpha.get_associated(); // returns a static lifetime reference to "Alpha"
 //! ```
 //!
 //! #### Generated Implementation
 //!
 //! ```rust
+//! # enum Phonetic { Alpha, Bravo = 3 }
 //! impl associated::Associated for Phonetic {
 //!     type AssociatedType = &'static str;
 //!     fn get_associated(&self) -> &'static Self::AssociatedType {
@@ -38,12 +41,262 @@
 //!                 const ASSOCIATED: &'static str = "Alpha";
 //!                 &ASSOCIATED
 //!             },
-//!             Phonetic::Bravo => &"Bravo",
+//!             Phonetic::Bravo => {
+//!                 let associated: &'static &'static str = &"Bravo";
+//!                 associated
+//!             },
 //!         }
 //!     }
 //! }
 //! ```
 //!
+//! All generated items are wrapped in an anonymous `const _: () = { ... };` block, so deriving adds
+//! nothing to the enum's module besides the trait implementation and documented inherent items.
+//! Several enums can derive with the same options side by side without their items colliding.
+//!
+//! ### Paths
+//!
+//! Associated expressions are resolved from the module containing the enum, so `crate::`, `super::`
+//! and external crate paths all work in both `#[assoc]` and `#[assoc_const]`.
+//!
+//! ### Generics
+//!
+//! Generic enums are supported, and the generated implementations reuse the enum's generics and
+//! where clause as is, so they combine with other derives such as `Clone` and `Copy`.
+//!
+//! `#[assoc_const]` expressions of an enum with const generics can use them, e.g. to compute sizes.
+//! They're evaluated in inline `const` blocks for such enums, so they need Rust 1.79, and only
+//! `get_associated` itself supports them.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated)]
+//! #[associated(Type = usize)]
+//! enum Buffer<const N: usize> {
+//!     #[assoc_const(N)] Single,
+//!     #[assoc_const({ N * 2 })] Double,
+//!     #[assoc_const(N * N + 1)] Square,
+//! }
+//!
+//! assert_eq!(Buffer::<4>::Single.get_associated(), &4);
+//! assert_eq!(Buffer::<4>::Double.get_associated(), &8);
+//! assert_eq!(Buffer::<3>::Square.get_associated(), &10);
+//! ```
+//!
+//! This includes types sized by them, such as arrays of a generic length.
+//!
+//! An enum can also be associated with its own variants, e.g. to encode a hierarchy. Unit variants
+//! are const, so they work with `#[assoc_const]`.
+//!
+//! ### Attribute Placement
+//!
+//! Like any attribute on a variant, `#[assoc]` and `#[assoc_const]` must come before the variant and
+//! its discriminant, either on the lines above it or inline. Doc comments and other attributes may sit
+//! between them and the variant.
+//!
+//! Attributes forwarded or generated by other macros, including those behind a `#[cfg_attr]` whose
+//! condition holds, are found the same way.
+//!
+//! ### Other Attributes
+//!
+//! Only the attributes the derive registers are read, so helper attributes of other derives can sit
+//! alongside them on the enum and its variants.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! use serde::Serialize;
+//!
+//! #[derive(Debug, Clone, Serialize, Associated)]
+//! #[serde(rename_all = "lowercase")]
+//! #[associated(Type = u8)]
+//! enum Level {
+//!     #[serde(rename = "lo")]
+//!     #[assoc_const(1)]
+//!     Low,
+//!     #[assoc_const(2)]
+//!     #[serde(skip)]
+//!     High,
+//! }
+//!
+//! assert_eq!(Level::Low.get_associated(), &1);
+//! assert_eq!(Level::High.clone().get_associated(), &2);
+//! ```
+//!
+//! Associations are namespaced by attribute name: each one reads only its own attributes, such as
+//! `assoc` and `assoc_const` for `get_associated`, or `assoc_secondary` and `assoc_secondary_const` for
+//! `get_secondary`. Derives of other association-style traits should follow the same convention with
+//! their own prefix, such as `#[assoc_label]`, so that every one of them can annotate the same
+//! variant without interfering with the others.
+//!
+//! `#[repr]` attributes, such as those on FFI enums, don't affect the derive.
+//!
+//! Options working with discriminants, such as `try_from` and `pairs`, take the integer type from
+//! `#[repr]` and skip any other reprs, such as `align(N)`, in the same or separate attributes.
+//!
+//! ### Conditional Derives
+//!
+//! `#[cfg_attr]` is expanded before the derive runs, so the derive only sees the attributes whose
+//! conditions hold. To only implement `Associated` when a feature is enabled, e.g. because `Type`
+//! comes from an optional dependency, put `derive(Associated)`, `#[associated]` and every `#[assoc]`
+//! or `#[assoc_const]` behind the same condition. Here `all()` and `any()` stand in for an enabled and
+//! a disabled `feature = "optdep"`.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[cfg_attr(all(), derive(Associated), associated(Type = u16))]
+//! enum Enabled {
+//!     #[cfg_attr(all(), assoc_const(80))] Http,
+//! }
+//!
+//! #[cfg_attr(any(), derive(Associated), associated(Type = optdep::Thing))]
+//! enum Disabled {
+//!     #[cfg_attr(any(), assoc_const(optdep::Thing::HTTP))] Http,
+//! }
+//!
+//! assert_eq!(Enabled::Http.get_associated(), &80);
+//! let _ = Disabled::Http;
+//! ```
+//!
+//! Gating only `#[associated]` is rejected, since the derive can't tell it was configured out.
+//!
+//! ### Const Blocks
+//!
+//! Inline `const { ... }` blocks, available since Rust 1.79, can be used for more involved const
+//! initialization in `#[assoc_const]`, or in `#[assoc]` behind a reference.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! const fn checksum(bytes: &[u8]) -> u8 {
+//!     let mut sum = 0u8;
+//!     let mut i = 0;
+//!     while i < bytes.len() {
+//!         sum = sum.wrapping_add(bytes[i]);
+//!         i += 1;
+//!     }
+//!     sum
+//! }
+//!
+//! #[derive(Associated)]
+//! #[associated(Type = u8)]
+//! enum Frame {
+//!     #[assoc_const(const { checksum(b"ping") })] Ping,
+//!     #[assoc(&const { checksum(b"pong") })] Pong,
+//! }
+//!
+//! assert_eq!(Frame::Ping.get_associated(), &checksum(b"ping"));
+//! assert_eq!(Frame::Pong.get_associated(), &checksum(b"pong"));
+//! ```
+//!
+//! `if` and `match` are const too, so with `cfg!`, which expands to a `bool` literal, a value can depend
+//! on the build configuration without a variant per configuration.
+//!
+//! ### Macros
+//!
+//! Associated expressions may be macro invocations, which are expanded inside the generated code. A
+//! macro used with `#[assoc_const]` must expand to a const expression.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! macro_rules! uuid_bytes {
+//!     ($($byte:literal)*) => { [$($byte),*] };
+//! }
+//!
+//! #[derive(Associated)]
+//! #[associated(Type = [u8; 4])]
+//! enum Kind {
+//!     #[assoc_const(uuid_bytes!(0xde 0xad 0xbe 0xef))] Dead,
+//!     #[assoc_const(uuid_bytes!(1 2 3 4))] Counting,
+//! }
+//!
+//! assert_eq!(Kind::Dead.get_associated(), &[0xde, 0xad, 0xbe, 0xef]);
+//! assert_eq!(Kind::Counting.get_associated(), &[1, 2, 3, 4]);
+//! ```
+//!
+//! A macro used with `#[assoc]` must expand to a `&'static Type` expression. A type mismatch is
+//! reported inside the macro's expansion, pointing back at the invocation on the variant.
+//!
+//! `env!` and `option_env!` expand to a `&'static str` and an `Option<&'static str>` respectively,
+//! so build-time metadata can be associated with `#[assoc_const]`.
+//!
+//! `include_str!` and `include_bytes!` produce `'static` data, so they can embed a resource per variant.
+//! As usual, paths are relative to the file containing the enum.
+//!
+//! ### Lazy Values
+//!
+//! With the `std` feature of `associated`, `#[assoc_lazy(expr)]` computes a value that can't be const
+//! the first time it's requested, storing it in a `OnceLock` for every later call, so `Type` must be
+//! `Send + Sync`. For `Type = &'static str`, `expr` may also be a `String`, such as one from
+//! `format!`, which is kept for the rest of the program.
+//!
+//! Since lazy values aren't const, they can't be used with options that need const values, such as
+//! `names` or `distinct`.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated)]
+//! #[associated(Type = &'static str)]
+//! enum Register {
+//!     #[assoc_lazy(format!("r{}", 0))] Zero,
+//!     #[assoc_lazy(format!("r{}", 1 + 1))] Two,
+//!     #[assoc_const("sp")] Stack,
+//! }
+//!
+//! assert_eq!(Register::Two.get_associated(), &"r2");
+//! assert!(std::ptr::eq(Register::Zero.get_associated(), Register::Zero.get_associated()));
+//! assert_eq!(Register::Stack.get_associated(), &"sp");
+//! ```
+//!
+//! Values of any `Send + Sync` type work, whether or not they're `Copy` or `Clone`, such as a regex
+//! compiled once per variant.
+//!
+//! Types from other crates can use `#[assoc_const]` wherever they have const constructors, such as
+//! `rust_decimal`'s `Decimal::from_parts`, and `#[assoc_lazy]` for those that aren't const, such as
+//! parsing.
+//!
+//! ### Uppercase Names
+//!
+//! `#[assoc_upper("name")]` associates a string literal converted to uppercase. `str::to_uppercase`
+//! isn't const, so the derive converts the literal itself and the value is an ordinary const, just
+//! like `#[assoc_const("NAME")]`. Requires `Type = &'static str`.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated)]
+//! #[associated(Type = &'static str, names)]
+//! enum Phonetic {
+//!     #[assoc_upper("alpha")] Alpha,
+//!     #[assoc_upper("straße")] Street,
+//!     #[assoc_const("mixed")] Mixed,
+//! }
+//!
+//! assert_eq!(Phonetic::Alpha.get_associated(), &"ALPHA");
+//! assert_eq!(Phonetic::NAMES, ["ALPHA", "STRASSE", "mixed"]);
+//! ```
+//!
+//! ### Debugging
+//!
+//! Every derive also generates `fn debug_associated(&self) -> (&'static str, &'static Type)`, pairing
+//! the name of the variant with its associated value, for logging or ad hoc debugging without deriving
+//! `Debug`.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated)]
+//! #[associated(Type = u16)]
+//! enum Port {
+//!     #[assoc_const(22)] Ssh,
+//!     #[assoc_const(8080)] Proxy { secure: bool },
+//! }
+//!
+//! assert_eq!(Port::Ssh.debug_associated(), ("Ssh", &22));
+//! assert_eq!(Port::Proxy { secure: false }.debug_associated(), ("Proxy", &8080));
+//! ```
+//!
+//! ### Structs and Unions
+//!
+//! Only enums can derive `Associated`. Structs of any kind and unions are rejected, whether or not
+//! they have an `#[associated]` attribute.
+//!
 //! ### Note
 //!
 //! If you give a variant both an `#[assoc]` and an `#[assoc_const]` attribute, or multiple `#[assoc]`
@@ -52,133 +305,3641 @@
 //! attribute per variant.
 //!
 //! See [associated](https://docs.rs/associated) for retrieving associated constants.
-
-use proc_macro::{self, TokenStream};
-use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
-use syn::{
-    parse::{Error as ParseError, Parse, ParseStream, Result as ParseResult},
-    parse_macro_input,
-    punctuated::Punctuated,
-    spanned::Spanned,
-    token::Comma,
-    Attribute, Binding, DeriveInput, Expr, Fields, Ident, Type, Variant,
-};
-
-struct Args {
-    assoc_type: Type,
-}
-
-enum AssocKind {
-    Constant,
-    Static,
-}
-
-struct Assoc<'a> {
-    kind: AssocKind,
-    attr: &'a Attribute,
+//!
+//! ## `AssociatedValue`
+//!
+//! `#[derive(AssociatedValue)]` accepts the same attributes, but implements `AssociatedValue`, which
+//! returns each value by value instead of by `'static` reference. `#[assoc_const]` values are returned
+//! as is and `#[assoc]` values are cloned, so types that can't be borrowed for `'static`, such as types
+//! with destructors, can be associated too. Only the options deciding values apply to it, which are
+//! `Type`, `default_name`, `rename_all`, `prefix`, `suffix`, `from_doc` and `via`, so others are
+//! rejected.
+//!
+//! ```rust
+//! # use associated::AssociatedValue;
+//! static SHARED: Vec<u8> = Vec::new();
+//!
+//! #[derive(AssociatedValue)]
+//! #[associated(Type = Vec<u8>)]
+//! enum Buffer {
+//!     #[assoc_const(Vec::new())] Fresh,
+//!     #[assoc(&SHARED)] Shared,
+//! }
+//!
+//! let mut buffer = Buffer::Fresh.associated_value();
+//! buffer.push(1);
+//! assert_eq!(buffer, [1]);
+//! assert!(Buffer::Shared.associated_value().is_empty());
+//! ```
+//!
+//! To derive both traits with other options, `AssociatedValue` can be given its own
+//! `#[associated_value]` attribute, which it reads instead of `#[associated]`. The variant attributes
+//! are shared.
+//!
+//! ```rust
+//! # use associated::{Associated, AssociatedValue};
+//! #[derive(Associated, AssociatedValue)]
+//! #[associated(Type = &'static str, names)]
+//! #[associated_value(Type = &'static str)]
+//! enum Phonetic {
+//!     #[assoc_const("Alpha")] Alpha,
+//!     #[assoc_const("Bravo")] Bravo,
+//! }
+//!
+//! assert_eq!(Phonetic::NAMES, ["Alpha", "Bravo"]);
+//! assert_eq!(Phonetic::Bravo.associated_value(), "Bravo");
+//! ```
+//!
+//! ## `AssociatedWith`
+//!
+//! `#[derive(AssociatedWith)]` implements `AssociatedWith<C>`, for values borrowed from a context of
+//! type `C` rather than `'static` ones, such as per-thread or per-request configuration. It takes an
+//! `#[associated_with(Type = T, Context = C)]` attribute, and an `#[assoc_with(expr)]` attribute on
+//! every variant, where `expr` is a `&T` that may borrow from `ctx: &C` and use `self`. Its attributes
+//! are separate from those of `Associated`, so an enum can derive both. Only `Type` and `Context`
+//! apply to it, and `Context` only to it, so other options are rejected.
+//!
+//! ```rust
+//! use associated::AssociatedWith;
+//! # use associated::Associated;
+//!
+//! struct Limits {
+//!     per_user: [u32; 2],
+//!     global: u32,
+//! }
+//!
+//! impl Limits {
+//!     fn per_user(&self, tier: &Tier) -> &u32 {
+//!         &self.per_user[*tier.get_associated()]
+//!     }
+//! }
+//!
+//! #[derive(Associated, AssociatedWith)]
+//! #[associated(Type = usize)]
+//! #[associated_with(Type = u32, Context = Limits)]
+//! enum Tier {
+//!     #[assoc_const(0)] #[assoc_with(ctx.per_user(self))] Free,
+//!     #[assoc_const(1)] #[assoc_with(ctx.per_user(self))] Paid,
+//!     #[assoc_const(0)] #[assoc_with(&ctx.global)] Unlimited,
+//! }
+//!
+//! let limits = Limits { per_user: [10, 100], global: 1000 };
+//! assert_eq!(Tier::Free.get_associated_with(&limits), &10);
+//! assert_eq!(Tier::Paid.get_associated_with(&limits), &100);
+//! assert_eq!(Tier::Unlimited.get_associated_with(&limits), &1000);
+//! ```
+//!
+//! ## `impl_associated!`
+//!
+//! Where `#[derive]` can't be put on an enum, such as one generated by another macro or declared in a
+//! module you'd rather not touch, `impl_associated!` implements `Associated` for it from a list of
+//! variants and const values instead of attributes. `Type` defaults to `&'static str` as with the
+//! derive, and every variant must be listed. The enum must still be local to your crate, since
+//! neither it nor `Associated` would otherwise be.
+//!
+//! ```rust
+//! use associated::{impl_associated, Associated};
+//!
+//! mod foreign {
+//!     pub enum Phonetic {
+//!         Alpha,
+//!         Bravo(u8),
+//!     }
+//! }
+//!
+//! impl_associated!(foreign::Phonetic, Type = &'static str, {
+//!     Alpha => "Alpha",
+//!     Bravo => "Bravo",
+//! });
+//!
+//! assert_eq!(foreign::Phonetic::Alpha.get_associated(), &"Alpha");
+//! assert_eq!(foreign::Phonetic::Bravo(2).get_associated(), &"Bravo");
+//! ```
+//!
+//! ## `assert_all_associated!`
+//!
+//! `assert_all_associated!(Enum, [Variant, ...])` fails to compile unless `Enum` implements
+//! `Associated` and the listed variants are exactly its variants. Kept next to code relying on the
+//! full set of variants, such as a table or a serialization format, it guards against variants added
+//! later without updating that code.
+//!
+//! ```rust
+//! use associated::{assert_all_associated, Associated};
+//!
+//! #[derive(Associated)]
+//! #[associated(Type = u8)]
+//! enum Level {
+//!     #[assoc_const(0)] Low,
+//!     #[assoc_const(1)] High,
+//! }
+//!
+//! assert_all_associated!(Level, [Low, High]);
+//! ```
+//!
+//! ## Type-Specific Methods
+//!
+//! Some associated types get extra inherent methods without needing an option.
+//!
+//! ### `&'static str`
+//!
+//! `const fn associated_len(&self) -> usize` returns the length in bytes of the associated string, and
+//! can be used in const contexts. Since `#[assoc]` expressions needn't be const, it's only generated
+//! when every variant uses `#[assoc_const]`.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated)]
+//! #[associated(Type = &'static str)]
+//! enum Phonetic {
+//!     #[assoc_const("Alpha")] Alpha,
+//!     #[assoc_const("Charlie")] Charlie,
+//! }
+//!
+//! const BUFFER_LEN: usize = Phonetic::Charlie.associated_len();
+//! let buffer = [0u8; BUFFER_LEN];
+//! assert_eq!(buffer.len(), 7);
+//! assert_eq!(Phonetic::Alpha.associated_len(), 5);
+//! ```
+//!
+//! `fn associated_bytes(&self) -> &'static [u8]` returns the bytes of the associated string, such as
+//! for hashing or writing to a socket.
+//!
+//! ### `&'static [T]`
+//!
+//! `fn associated_iter(&self) -> core::slice::Iter<'static, T>` iterates over the associated slice.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! use std::time::Duration;
+//!
+//! #[derive(Associated)]
+//! #[associated(Type = &'static [Duration])]
+//! enum RetryPolicy {
+//!     #[assoc_const(&[])] Never,
+//!     #[assoc_const(&[Duration::from_secs(1), Duration::from_secs(2), Duration::from_secs(4)])]
+//!     Backoff,
+//! }
+//!
+//! assert_eq!(RetryPolicy::Never.associated_iter().count(), 0);
+//! let total: Duration = RetryPolicy::Backoff.associated_iter().sum();
+//! assert_eq!(total, Duration::from_secs(7));
+//! ```
+//!
+//! ### `&'static [T; N]`
+//!
+//! `const fn associated_len(&self) -> usize` returns `N`, which suits fixed-size values such as
+//! protocol headers given as byte strings. A byte string of the wrong length is an error at the
+//! literal.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated)]
+//! #[associated(Type = &'static [u8; 4])]
+//! enum Header {
+//!     #[assoc_const(b"RIFF")] Riff,
+//!     #[assoc_const(b"\x89PNG")] Png,
+//! }
+//!
+//! const HEADER_LEN: usize = Header::Riff.associated_len();
+//! assert_eq!(HEADER_LEN, 4);
+//! assert_eq!(Header::Png.get_associated(), &b"\x89PNG");
+//! ```
+//!
+//! ### `Option<&'static str>`
+//!
+//! `fn associated_or_empty(&self) -> &'static str` returns the associated string, or `""` for `None`.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated)]
+//! #[associated(Type = Option<&'static str>)]
+//! enum Field {
+//!     #[assoc_const(Some("identifier"))] Id,
+//!     #[assoc_const(None)] Padding,
+//! }
+//!
+//! assert_eq!(Field::Id.associated_or_empty(), "identifier");
+//! assert_eq!(Field::Padding.associated_or_empty(), "");
+//! ```
+//!
+//! ### `Cow<'static, str>`
+//!
+//! `fn associated_str(&self) -> &'static str` returns the associated string whether it's borrowed or
+//! owned. `Cow::Borrowed` is const, so it can be used with `#[assoc_const]`.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! use std::borrow::Cow;
+//!
+//! #[derive(Associated)]
+//! #[associated(Type = Cow<'static, str>)]
+//! enum Phonetic {
+//!     #[assoc_const(Cow::Borrowed("Alpha"))] Alpha,
+//!     #[assoc_const(Cow::Borrowed("Bravo"))] Bravo,
+//! }
+//!
+//! assert_eq!(Phonetic::Alpha.associated_str(), "Alpha");
+//! assert_eq!(Phonetic::Bravo.get_associated(), "Bravo");
+//! ```
+//!
+//! ### `&'static CStr`
+//!
+//! `fn as_ptr(&self) -> *const c_char` returns a pointer to the associated C string for passing over
+//! FFI. C string literals like `c"Alpha"`, available since Rust 1.77, are `&'static CStr` consts.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! use std::ffi::{c_char, CStr};
+//!
+//! #[derive(Associated)]
+//! #[associated(Type = &'static CStr)]
+//! enum Symbol {
+//!     #[assoc_const(c"Alpha")] Alpha,
+//!     #[assoc(&c"Bravo")] Bravo,
+//! }
+//!
+//! let pointer: *const c_char = Symbol::Alpha.as_ptr();
+//! // SAFETY: the pointer comes from a `&'static CStr`.
+//! assert_eq!(unsafe { CStr::from_ptr(pointer) }, c"Alpha");
+//! assert_eq!(Symbol::Bravo.get_associated().to_bytes(), b"Bravo");
+//! ```
+//!
+//! ### `&'static Path`
+//!
+//! Since `Path::new` isn't const, `&'static std::path::Path` values are given with `#[assoc_lazy]`.
+//! `fn associated_str(&self) -> Option<&'static str>` returns the path as a string if it's valid
+//! Unicode. It's only generated for `Path` written as `Path`, `path::Path` or `std::path::Path`, so
+//! user types named `Path` aren't mistaken for it.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! use std::path::Path;
+//!
+//! #[derive(Associated)]
+//! #[associated(Type = &'static Path)]
+//! enum Config {
+//!     #[assoc_lazy(Path::new("/etc/foo.toml"))] System,
+//!     #[assoc_lazy(Path::new("foo.toml"))] Local,
+//! }
+//!
+//! assert_eq!(*Config::System.get_associated(), Path::new("/etc/foo.toml"));
+//! assert!(Config::Local.get_associated().is_relative());
+//! assert_eq!(Config::Local.associated_str(), Some("foo.toml"));
+//! ```
+//!
+//! ### `Ordering`
+//!
+//! For `Type = Ordering`, as in `core::cmp::Ordering`, `fn is_less(&self) -> bool`, `fn is_equal(&self)
+//! -> bool` and `fn is_greater(&self) -> bool` check the associated ordering. They're only generated
+//! for `Ordering` written as `Ordering`, `cmp::Ordering` or `core::cmp::Ordering`, so other types
+//! named `Ordering`, such as `core::sync::atomic::Ordering`, aren't mistaken for it.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! use core::cmp::Ordering;
+//!
+//! #[derive(Associated)]
+//! #[associated(Type = Ordering)]
+//! enum Version {
+//!     #[assoc_const(Ordering::Less)] Outdated,
+//!     #[assoc_const(Ordering::Equal)] Current,
+//!     #[assoc_const(Ordering::Greater)] Preview,
+//! }
+//!
+//! assert_eq!(Version::Outdated.get_associated(), &Ordering::Less);
+//! assert!(Version::Outdated.is_less());
+//! assert!(Version::Current.is_equal());
+//! assert!(Version::Preview.is_greater());
+//! assert!(!Version::Preview.is_less());
+//! ```
+//!
+//! ### Shared State
+//!
+//! Types with interior mutability, such as `Type = Mutex<u32>`, give each variant its own shared
+//! mutable state. Such values must live in a `static` associated with `#[assoc]`, since `'static`
+//! references to interior mutable `#[assoc_const]` values aren't allowed. `Mutex::new` is const, so
+//! no lazy initialization is needed.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! use std::sync::Mutex;
+//!
+//! static READS: Mutex<u32> = Mutex::new(0);
+//! static WRITES: Mutex<u32> = Mutex::new(0);
+//!
+//! #[derive(Associated)]
+//! #[associated(Type = Mutex<u32>)]
+//! enum Operation {
+//!     #[assoc(&READS)] Read,
+//!     #[assoc(&WRITES)] Write,
+//! }
+//!
+//! fn record(operation: Operation) {
+//!     *operation.get_associated().lock().unwrap() += 1;
+//! }
+//!
+//! record(Operation::Read);
+//! record(Operation::Read);
+//! record(Operation::Write);
+//! assert_eq!(*Operation::Read.get_associated().lock().unwrap(), 2);
+//! assert_eq!(*WRITES.lock().unwrap(), 1);
+//! ```
+//!
+//! ### `fn` Pointers
+//!
+//! Function pointer types such as `Type = fn(u32) -> u32` associate a named function with every
+//! variant, making a dispatch table. `#[assoc_const]` coerces function item names to the pointer type,
+//! while `#[assoc]` needs a cast, as in `#[assoc(&(double as fn(u32) -> u32))]`, since references to
+//! function items don't coerce.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! fn double(x: u32) -> u32 {
+//!     x * 2
+//! }
+//!
+//! fn square(x: u32) -> u32 {
+//!     x * x
+//! }
+//!
+//! #[derive(Associated)]
+//! #[associated(Type = fn(u32) -> u32)]
+//! enum Op {
+//!     #[assoc_const(double)] Double,
+//!     #[assoc_const(square)] Square,
+//!     #[assoc(&((|x| x + 1) as fn(u32) -> u32))] Increment,
+//! }
+//!
+//! assert_eq!((Op::Double.get_associated())(4), 8);
+//! assert_eq!((Op::Square.get_associated())(4), 16);
+//! assert_eq!((Op::Increment.get_associated())(4), 5);
+//! ```
+//!
+//! ## Self-Referential Types
+//!
+//! `Self` can be used within `Type`, such as to associate each variant with another. It's replaced
+//! with the enum's type, so `#[assoc_const]` works with it despite the const being a nested item.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated, Debug, PartialEq)]
+//! #[associated(Type = &'static Self)]
+//! enum Direction {
+//!     #[assoc_const(&Direction::South)] North,
+//!     #[assoc_const(&Direction::North)] South,
+//!     #[assoc(&&Direction::West)] East,
+//!     #[assoc(&&Direction::East)] West,
+//! }
+//!
+//! assert_eq!(*Direction::North.get_associated(), &Direction::South);
+//! assert_eq!(*Direction::West.get_associated(), &Direction::East);
+//! ```
+//!
+//! ## Unreachable Variants
+//!
+//! A variant that should never be constructed, such as one kept for compatibility, can be given a
+//! diverging expression like `#[assoc(unreachable!())]`. Having type `!`, it coerces to
+//! `&'static Type`, so the arm returns nothing and panics if it's ever reached.
+//!
+//! ```rust,should_panic
+//! # use associated::Associated;
+//! #[derive(Associated)]
+//! #[associated(Type = u16)]
+//! enum Opcode {
+//!     #[assoc_const(0x01)] Load,
+//!     #[assoc(unreachable!("`Legacy` is never constructed"))] Legacy,
+//! }
+//!
+//! assert_eq!(*Opcode::Load.get_associated(), 0x01);
+//! Opcode::Legacy.get_associated(); // panics
+//! ```
+//!
+//! With `#[assoc_const]`, the expression is evaluated at compile time, so it's a compile error
+//! instead.
+//!
+//! ## Options
+//!
+//! Further items can be generated by adding options to the `#[associated]` attribute, e.g.
+//! `#[associated(Type = &'static str, names)]` or just `#[associated(names)]`.
+//!
+//! ### `names`
+//!
+//! Generates a `NAMES` const listing the associated string of every variant in declaration order.
+//! Requires `Type = &'static str` and an enum with only unit variants.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated)]
+//! #[associated(Type = &'static str, names)]
+//! enum Phonetic {
+//!     #[assoc_const("Alpha")] Alpha,
+//!     #[assoc(&"Bravo")] Bravo,
+//! }
+//!
+//! assert_eq!(Phonetic::NAMES.join(", "), "Alpha, Bravo");
+//! ```
+//!
+//! ### `contains`
+//!
+//! Generates `fn associated_contains(&self, value: &T) -> bool` for `Type = &'static [T]`, checking
+//! whether the associated slice contains `value`. For slices of `&'static str`, it takes `s: &str`.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated)]
+//! #[associated(Type = &'static [&'static str], contains)]
+//! enum Command {
+//!     #[assoc_const(&["q", "quit", "exit"])] Quit,
+//!     #[assoc_const(&["h", "help"])] Help,
+//! }
+//!
+//! let input = String::from("exit");
+//! assert!(Command::Quit.associated_contains(&input));
+//! assert!(!Command::Help.associated_contains("quit"));
+//! assert_eq!(Command::Help.get_associated(), &["h", "help"]);
+//! ```
+//!
+//! For `Type = Range<T>` or `RangeInclusive<T>`, it's `fn associated_contains(&self, value: T) -> bool`.
+//! Range expressions are const, so they can be used with `#[assoc_const]`.
+//!
+//! ### `debug`
+//!
+//! Generates a `Debug` implementation that formats the associated value with its own `Debug`
+//! implementation, instead of the variant name.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated)]
+//! #[associated(Type = &'static str, debug)]
+//! enum Phonetic {
+//!     #[assoc_const("alpha")] Alpha,
+//! }
+//!
+//! assert_eq!(format!("{:?}", Phonetic::Alpha), "\"alpha\"");
+//! ```
+//!
+//! ### `default_name`
+//!
+//! Associates unit variants without an `#[assoc]` or `#[assoc_const]` attribute with their name, so
+//! only the exceptions need an attribute. Raw identifiers are named without `r#`. Requires
+//! `Type = &'static str`.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated)]
+//! #[associated(Type = &'static str, default_name)]
+//! #[allow(non_camel_case_types)]
+//! enum Phonetic {
+//!     Alpha,
+//!     #[assoc_const("bravo!")] Bravo,
+//!     Charlie,
+//!     r#in,
+//! }
+//!
+//! assert_eq!(Phonetic::Alpha.get_associated(), &"Alpha");
+//! assert_eq!(Phonetic::Bravo.get_associated(), &"bravo!");
+//! assert_eq!(Phonetic::Charlie.get_associated(), &"Charlie");
+//! assert_eq!(Phonetic::r#in.get_associated(), &"in");
+//! ```
+//!
+//! `rename_all = "casing"` converts these default names to one of serde's casings: `"lowercase"`,
+//! `"UPPERCASE"`, `"PascalCase"`, `"camelCase"`, `"snake_case"`, `"SCREAMING_SNAKE_CASE"`,
+//! `"kebab-case"` or `"SCREAMING-KEBAB-CASE"`. It enables default names itself, and the conversion
+//! happens during expansion, so the names stay const.
+//!
+//! `prefix = "..."` and `suffix = "..."` add to the default names after any `rename_all` casing, and
+//! also enable default names themselves.
+//!
+//! ### `from_doc`
+//!
+//! Associates variants without an `#[assoc]` or `#[assoc_const]` attribute with their doc comment,
+//! trimming each line and the text as a whole. Variants with neither are rejected. Requires
+//! `Type = &'static str`.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated)]
+//! #[associated(Type = &'static str, from_doc)]
+//! enum Command {
+//!     /// Runs the build.
+//!     Run,
+//!     /// Removes build artifacts.
+//!     /// Keeps downloaded dependencies.
+//!     Clean { all: bool },
+//!     #[assoc_const("Prints this message.")] Help,
+//! }
+//!
+//! assert_eq!(Command::Run.get_associated(), &"Runs the build.");
+//! assert_eq!(
+//!     Command::Clean { all: true }.get_associated(),
+//!     &"Removes build artifacts.\nKeeps downloaded dependencies."
+//! );
+//! assert_eq!(Command::Help.get_associated(), &"Prints this message.");
+//! ```
+//!
+//! ### `into`
+//!
+//! Generates `impl From<Enum> for Type`, copying the associated value out of each variant. Requires a
+//! `Copy` associated type and an enum with only unit variants.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated)]
+//! #[associated(Type = u8, into)]
+//! enum Priority {
+//!     #[assoc_const(1)] Low,
+//!     #[assoc_const(10)] High,
+//! }
+//!
+//! let n: u8 = Priority::High.into();
+//! assert_eq!(n, 10);
+//! assert_eq!(u8::from(Priority::Low), 1);
+//! ```
+//!
+//! ### `expose_consts`
+//!
+//! Generates a public `<VARIANT>_ASSOCIATED` const on the enum for every `#[assoc_const]` variant,
+//! named after the variant in `SCREAMING_SNAKE_CASE`, without the `r#` of raw identifiers.
+//! `#[assoc]` variants don't get a const since their values are references. The consts are `pub`, so
+//! they can be read from outside the enum's module.
+//!
+//! ```rust
+//! mod phonetic {
+//!     # use associated::Associated;
+//!     #[derive(Associated)]
+//!     #[associated(Type = u32, expose_consts)]
+//!     #[allow(non_camel_case_types)]
+//!     pub enum Phonetic {
+//!         #[assoc_const(1)] Alpha,
+//!         #[assoc_const(2)] BravoCharlie,
+//!         #[assoc(&3)] Delta,
+//!         #[assoc_const(4)] r#loop,
+//!     }
+//! }
+//!
+//! use phonetic::Phonetic;
+//! assert_eq!(Phonetic::ALPHA_ASSOCIATED, 1);
+//! assert_eq!(Phonetic::BRAVO_CHARLIE_ASSOCIATED, 2);
+//! assert_eq!(Phonetic::LOOP_ASSOCIATED, 4);
+//! ```
+//!
+//! The consts are associated items, so any variant's value can refer to any other variant's const
+//! regardless of declaration order, as long as the references aren't cyclic.
+//!
+//! Variants whose names convert to the same const, such as `AlphaBravo` and `Alpha_Bravo`, are
+//! rejected.
+//!
+//! ### `distinct`
+//!
+//! Fails compilation if two variants have equal associated values, pointing at the duplicate.
+//! Integers, `char` and `&'static str` are compared by const assertions. Values of other types are
+//! used as const patterns and duplicates are reported by the `unreachable_patterns` lint, which the
+//! derive denies but `--cap-lints` can weaken, so `Type` must be usable in patterns, i.e. a primitive
+//! or a type deriving both `PartialEq` and `Eq`.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated)]
+//! #[associated(Type = &'static str, distinct)]
+//! enum Key {
+//!     #[assoc_const("alpha")] Alpha,
+//!     #[assoc(&"bravo")] Bravo,
+//! }
+//! ```
+//!
+//! ### `ord_by_assoc`
+//!
+//! Generates `PartialOrd` and `Ord` implementations comparing associated values, which requires
+//! `Type: Ord`. This replaces the usual declaration order of variants, so `PartialEq` and `Eq` must be
+//! implemented consistently, e.g. by deriving them when associated values are distinct.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated, Debug, PartialEq, Eq)]
+//! #[associated(Type = u32, ord_by_assoc)]
+//! enum Priority {
+//!     #[assoc_const(10)] High,
+//!     #[assoc_const(0)] Low,
+//!     #[assoc_const(5)] Medium,
+//! }
+//!
+//! let mut tasks = vec![Priority::High, Priority::Low, Priority::Medium];
+//! tasks.sort();
+//! assert_eq!(tasks, [Priority::Low, Priority::Medium, Priority::High]);
+//! ```
+//!
+//! ### `SecondaryType`
+//!
+//! `SecondaryType = T` associates every variant with a second constant of type `T`, given with
+//! `#[assoc_secondary(expr)]` or `#[assoc_secondary_const(const_expr)]` in the same way as `#[assoc]`
+//! and `#[assoc_const]`. It's returned by the generated `fn get_secondary(&self) -> &'static T`.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated)]
+//! #[associated(Type = &'static str, SecondaryType = u32)]
+//! enum Phonetic {
+//!     #[assoc_const("Alpha")]
+//!     #[assoc_secondary_const(1)]
+//!     Alpha,
+//!     #[assoc_const("Bravo")]
+//!     #[assoc_secondary(&2)]
+//!     Bravo,
+//! }
+//!
+//! assert_eq!(Phonetic::Alpha.get_associated(), &"Alpha");
+//! assert_eq!(Phonetic::Bravo.get_secondary(), &2);
+//! ```
+//!
+//! Every variant needs a secondary value.
+//!
+//! ### `via`
+//!
+//! `via = path` computes every variant's value by calling the const fn at `path` with the variant,
+//! so associations kept in a separate `const fn` lookup needn't be repeated in attributes. The
+//! computed values are still const. Requires an enum with only unit variants.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated)]
+//! #[associated(Type = u32, via = color_rgb)]
+//! enum Color {
+//!     Red,
+//!     Green,
+//!     Blue,
+//! }
+//!
+//! const fn color_rgb(color: Color) -> u32 {
+//!     match color {
+//!         Color::Red => 0xff0000,
+//!         Color::Green => 0x00ff00,
+//!         Color::Blue => 0x0000ff,
+//!     }
+//! }
+//!
+//! assert_eq!(Color::Green.get_associated(), &0x00ff00);
+//! assert_eq!(*Color::Red.get_associated(), color_rgb(Color::Red));
+//! ```
+//!
+//! A variant with its own attribute uses that value instead, so `via` can compute defaults that a
+//! few variants override.
+//!
+//! ### `id`
+//!
+//! `id = T` pairs every variant's associated constant with an id of type `T`, both given in one
+//! `#[assoc_pair(value, id)]` attribute, which every variant must have. Both are const expressions.
+//! The id is returned by the generated `const fn id(&self) -> T`.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated)]
+//! #[associated(Type = &'static str, id = u32)]
+//! enum Phonetic {
+//!     #[assoc_pair("Alpha", 1)] Alpha,
+//!     #[assoc_pair("Bravo", 2)] Bravo,
+//! }
+//!
+//! assert_eq!(Phonetic::Alpha.get_associated(), &"Alpha");
+//! assert_eq!(Phonetic::Bravo.id(), 2);
+//! ```
+//!
+//! ### `const_fn`
+//!
+//! Generates `const fn associated_const(&self) -> Type`, returning the associated constant by value in
+//! const contexts, since trait methods such as `get_associated` can't be const. For integer types, it
+//! also generates `const fn associated_eq(&self, other: Type) -> bool`, for compile-time validation.
+//! Every value must be `#[assoc_const]`, since `#[assoc]` and `#[assoc_lazy]` values are only
+//! available by reference.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated)]
+//! #[associated(Type = u16, const_fn)]
+//! enum Port {
+//!     #[assoc_const(80)] Http,
+//!     #[assoc_const(443)] Https,
+//! }
+//!
+//! const HTTPS: u16 = Port::Https.associated_const();
+//! const _: () = assert!(Port::Http.associated_eq(80));
+//! assert_eq!(HTTPS, 443);
+//! assert!(!Port::Https.associated_eq(80));
+//! ```
+//!
+//! ### `associated_at`
+//!
+//! Generates `const fn associated_at(index: usize) -> &'static Type`, looking values up by index
+//! into a const array. It's total over `0..N`, so it returns the value rather than an `Option`, and
+//! panics out of bounds, which is a compile error in const contexts. Requires an enum without const
+//! generics with only unit variants, with the discriminants `0..N` in declaration order, and
+//! `#[assoc_const]` for every variant.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated)]
+//! #[associated(Type = &'static str, associated_at)]
+//! enum Level {
+//!     #[assoc_const("debug")] Debug,
+//!     #[assoc_const("info")] Info = 1,
+//!     #[assoc_const("warn")] Warn,
+//! }
+//!
+//! const FIRST: &str = Level::associated_at(0);
+//! assert_eq!(FIRST, "debug");
+//! assert_eq!(Level::associated_at(Level::Warn as usize), Level::Warn.get_associated());
+//! ```
+//!
+//! ### `from_associated`
+//!
+//! Generates `fn from_associated(value: &Type) -> Option<Self>`, returning the first variant
+//! associated with `value`. Requires `Type: PartialEq` and an enum with only unit variants.
+//!
+//! For integer types, when every variant uses `#[assoc_const]`, it also generates
+//! `const fn from_associated_const(value: Type) -> Option<Self>` for use in const contexts.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated, Debug, PartialEq)]
+//! #[associated(Type = u16, from_associated)]
+//! enum Port {
+//!     #[assoc_const(80)] Http,
+//!     #[assoc_const(443)] Https,
+//! }
+//!
+//! const HTTPS: Option<Port> = Port::from_associated_const(443);
+//! assert_eq!(HTTPS, Some(Port::Https));
+//! assert_eq!(Port::from_associated(&80), Some(Port::Http));
+//! assert_eq!(Port::from_associated(&22), None);
+//! ```
+//!
+//! For `Type = &'static str` where every value is a string literal, the strings are sorted when the
+//! enum is derived, into `const SORTED: &'static [(&'static str, Self)]`. `from_associated` then
+//! binary searches `SORTED` instead of comparing with each variant in turn.
+//!
+//! With the `alloc` feature of `associated`, it also generates `fn all_from_associated(value: &Type)
+//! -> Vec<Self>`, returning every variant associated with `value`, for enums with synonymous
+//! variants.
+//!
+//! For `Type = &'static [&'static str]`, such as a set of aliases per variant, it also generates
+//! `fn from_any_associated(s: &str) -> Option<Self>`, returning the first variant whose associated
+//! slice contains `s`.
+//!
+//! ### `index_of_associated`
+//!
+//! Generates `const fn index_of_associated(value: Type) -> Option<usize>`, returning the declaration
+//! index of the first variant associated with `value`. Requires an integer or `char` `Type` and const
+//! values. With `associated_at`, it makes a two-way const table.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated)]
+//! #[associated(Type = char, index_of_associated, associated_at)]
+//! enum Marker {
+//!     #[assoc_const('*')] Bullet,
+//!     #[assoc_const('#')] Heading,
+//!     #[assoc_const('>')] Quote,
+//! }
+//!
+//! const QUOTE: Option<usize> = Marker::index_of_associated('>');
+//! assert_eq!(QUOTE, Some(2));
+//! assert_eq!(Marker::associated_at(2), &'>');
+//! assert_eq!(Marker::index_of_associated('-'), None);
+//! ```
+//!
+//! ### `eq_value`
+//!
+//! Generates `impl PartialEq<Type>` for the enum, comparing the associated value with the other side.
+//! For `Type = &'static str`, it instead generates `PartialEq<str>` and `PartialEq<&str>`, so
+//! variants can be compared with string literals directly.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated)]
+//! #[associated(Type = &'static str, eq_value)]
+//! enum Phonetic {
+//!     #[assoc_const("Alpha")] Alpha,
+//!     #[assoc_const("Bravo")] Bravo,
+//! }
+//!
+//! assert!(Phonetic::Alpha == "Alpha");
+//! assert!(Phonetic::Bravo != "Alpha");
+//! assert!(Phonetic::Bravo == *String::from("Bravo"));
+//! ```
+//!
+//! ### `try_from`
+//!
+//! Generates `TryFrom<R>` for the enum, converting a discriminant back to its variant, where `R` is
+//! the integer type in the enum's `#[repr]` attribute, or `isize` without one. The error is the value
+//! that didn't match any discriminant. Requires an enum with only unit variants.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated, Debug, PartialEq)]
+//! #[associated(Type = &'static str, try_from)]
+//! #[repr(u8)]
+//! enum Opcode {
+//!     #[assoc_const("nop")] Nop = 0x00,
+//!     #[assoc_const("jump")] Jump = 0x10,
+//!     #[assoc_const("halt")] Halt,
+//! }
+//!
+//! assert_eq!(Opcode::try_from(0x10u8), Ok(Opcode::Jump));
+//! assert_eq!(Opcode::try_from(0x11u8), Ok(Opcode::Halt));
+//! assert_eq!(Opcode::try_from(0xffu8), Err(0xff));
+//! ```
+//!
+//! ### `value_enum`
+//!
+//! With the `clap` feature of `associated`, generates `clap::ValueEnum` for the enum, using the
+//! associated strings as the value names, so it can be parsed from command line arguments. Requires
+//! `Type = &'static str`, `Clone` and an enum with only unit variants.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! use clap::{builder::EnumValueParser, Arg, Command};
+//!
+//! #[derive(Associated, Clone, Debug, PartialEq)]
+//! #[associated(Type = &'static str, value_enum)]
+//! enum Format {
+//!     #[assoc_const("json")] Json,
+//!     #[assoc_const("yaml")] Yaml,
+//! }
+//!
+//! let command = Command::new("app")
+//!     .arg(Arg::new("format").long("format").value_parser(EnumValueParser::<Format>::new()));
+//! let matches = command.clone().get_matches_from(["app", "--format", "yaml"]);
+//! assert_eq!(matches.get_one::<Format>("format"), Some(&Format::Yaml));
+//! assert!(command.try_get_matches_from(["app", "--format", "Yaml"]).is_err());
+//! ```
+//!
+//! ### `display_padded`
+//!
+//! Generates `Display`, writing the associated string left-aligned and padded with spaces to the
+//! length of the longest one, which is computed at compile time. This lines up tables of variants.
+//! Requires `Type = &'static str` with const values.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated)]
+//! #[associated(Type = &'static str, display_padded)]
+//! enum Status {
+//!     #[assoc_const("ok")] Ok,
+//!     #[assoc_const("failed")] Failed,
+//!     #[assoc(&"skipped")] Skipped,
+//! }
+//!
+//! let rows: Vec<_> = [Status::Ok, Status::Failed, Status::Skipped]
+//!     .iter()
+//!     .map(|status| format!("{}|", status))
+//!     .collect();
+//! assert_eq!(rows, ["ok     |", "failed |", "skipped|"]);
+//! ```
+//!
+//! ### `validate`
+//!
+//! `validate = ident` checks at compile time that every associated string is a valid Rust
+//! identifier, such as for code generation, erroring at the first that isn't. Requires
+//! `Type = &'static str` and string literal values. Raw identifiers like `"r#type"` are valid, but
+//! keywords and strings with whitespace or comments around the identifier aren't.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated)]
+//! #[associated(Type = &'static str, validate = ident)]
+//! enum Field {
+//!     #[assoc_const("user_id")] UserId,
+//!     #[assoc_const("r#type")] Type,
+//! }
+//! ```
+//!
+//! ### `from_str`
+//!
+//! Generates `FromStr` for the enum, parsing each variant from its associated string. Its error,
+//! `associated::ParseAssociatedError`, lists every associated string, and displays them as
+//! "expected one of: ...". Requires `Type = &'static str`, an enum with only unit variants, and const
+//! values for the list.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated, Debug, PartialEq)]
+//! #[associated(Type = &'static str, from_str)]
+//! enum Level {
+//!     #[assoc_const("debug")] Debug,
+//!     #[assoc_const("info")] Info,
+//!     #[assoc_const("warn")] Warn,
+//! }
+//!
+//! assert_eq!("info".parse(), Ok(Level::Info));
+//! let error = "trace".parse::<Level>().unwrap_err();
+//! assert_eq!(error.to_string(), "expected one of: `debug`, `info`, `warn`");
+//! ```
+//!
+//! ### `from_str_loose`
+//!
+//! Generates `fn from_associated_normalized(s: &str) -> Option<Self>`, a reverse lookup for human
+//! input that trims surrounding whitespace and ignores ASCII case on both sides before comparing.
+//! Requires `Type = &'static str` and an enum with only unit variants.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated, Debug, PartialEq)]
+//! #[associated(Type = &'static str, from_str_loose)]
+//! enum Phonetic {
+//!     #[assoc_const("Alpha")] Alpha,
+//!     #[assoc_const("Bravo ")] Bravo,
+//! }
+//!
+//! assert_eq!(Phonetic::from_associated_normalized("  alpha "), Some(Phonetic::Alpha));
+//! assert_eq!(Phonetic::from_associated_normalized("BRAVO"), Some(Phonetic::Bravo));
+//! assert_eq!(Phonetic::from_associated_normalized("al pha"), None);
+//! ```
+//!
+//! ### `bind_fields`
+//!
+//! Binds the fields of every variant in `get_associated`, so `#[assoc]` expressions can choose a value
+//! from them. Named fields are bound by name and tuple fields as `_0`, `_1` and so on, all by
+//! reference. The chosen value must still be `'static`, so fields select among values rather than
+//! being returned themselves, and `#[assoc_const]` values can't use them.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated)]
+//! #[associated(Type = &'static str, bind_fields)]
+//! enum Light {
+//!     #[assoc(if *on { &"on" } else { &"off" })] Switch { on: bool },
+//!     #[assoc(if *_0 > 50 { &"bright" } else { &"dim" })] Dimmer(u8),
+//!     #[assoc_const("unknown")] Other(u8),
+//! }
+//!
+//! assert_eq!(Light::Switch { on: true }.get_associated(), &"on");
+//! assert_eq!(Light::Dimmer(80).get_associated(), &"bright");
+//! assert_eq!(Light::Dimmer(20).get_associated(), &"dim");
+//! assert_eq!(Light::Other(1).get_associated(), &"unknown");
+//! ```
+//!
+//! ### `include_field`
+//!
+//! `include_field = N` generates `fn with_field_associated(&self) -> (F, &'static Type)`, returning a
+//! clone of tuple field `N` alongside the associated constant. Every variant must have a tuple field
+//! `N`, all of the same type `F`.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated)]
+//! #[associated(Type = &'static str, include_field = 0)]
+//! enum Op {
+//!     #[assoc_const("add")] Add(u8),
+//!     #[assoc_const("sub")] Sub(u8, bool),
+//! }
+//!
+//! assert_eq!(Op::Add(3).with_field_associated(), (3, &"add"));
+//! assert_eq!(Op::Sub(5, true).with_field_associated(), (5, &"sub"));
+//! ```
+//!
+//! ### `case_insensitive`
+//!
+//! Generates `fn matches_associated_ignore_ascii_case(&self, s: &str) -> bool`, comparing the
+//! associated string with `s` using [`str::eq_ignore_ascii_case`]. Requires `Type = &'static str`.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated)]
+//! #[associated(Type = &'static str, case_insensitive)]
+//! enum Phonetic {
+//!     #[assoc_const("Alpha")] Alpha,
+//!     #[assoc_const("Bravo")] Bravo,
+//! }
+//!
+//! assert!(Phonetic::Alpha.matches_associated_ignore_ascii_case("ALPHA"));
+//! assert!(Phonetic::Bravo.matches_associated_ignore_ascii_case("bRaVo"));
+//! assert!(!Phonetic::Bravo.matches_associated_ignore_ascii_case("Alpha"));
+//! ```
+//!
+//! ### `pairs`
+//!
+//! Generates `const PAIRS: &'static [(i64, &'static Type)]`, pairing every variant's discriminant with
+//! its associated constant, sorted by discriminant at compile time. `fn associated_by_discriminant(
+//! discriminant: i64) -> Option<&'static Type>` looks values up with a binary search over it. Requires
+//! an enum with only unit variants and const values.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated)]
+//! #[associated(Type = &'static str, pairs)]
+//! enum Status {
+//!     #[assoc_const("not found")] NotFound = 404,
+//!     #[assoc_const("ok")] Ok = 200,
+//!     #[assoc(&"moved")] Moved = 301,
+//! }
+//!
+//! assert_eq!(Status::PAIRS, [(200, &"ok"), (301, &"moved"), (404, &"not found")]);
+//! assert_eq!(Status::associated_by_discriminant(301), Some(&"moved"));
+//! assert_eq!(Status::associated_by_discriminant(500), None);
+//! ```
+//!
+//! ### `resolve`
+//!
+//! For `Type = &'static [T]`, generates `fn resolve(&self) -> Option<&'static T>`, indexing the
+//! associated slice by the variant's first tuple field, which must be a `usize`. Returns `None` if the
+//! index is out of bounds.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated)]
+//! #[associated(Type = &'static [&'static str], resolve)]
+//! enum Cmd {
+//!     #[assoc_const(&["run", "start"])] Run(usize),
+//!     #[assoc_const(&["stop"])] Stop(usize, bool),
+//! }
+//!
+//! assert_eq!(Cmd::Run(1).resolve(), Some(&"start"));
+//! assert_eq!(Cmd::Stop(0, true).resolve(), Some(&"stop"));
+//! assert_eq!(Cmd::Stop(1, false).resolve(), None);
+//! ```
+//!
+//! ### `predicates`
+//!
+//! `predicates` generates a `const fn is_<variant>(&self) -> bool` for every variant, named after the
+//! variant in snake case, as a shorthand for `matches!`. Variants with fields are matched whatever
+//! their fields. Raw identifiers are named without `r#`. Two variants with the same snake case name,
+//! such as `HttpServer` and `HTTPServer`, are an error.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated)]
+//! #[associated(Type = &'static str, predicates)]
+//! #[allow(non_camel_case_types)]
+//! enum Shape {
+//!     #[assoc_const("circle")] Circle(f32),
+//!     #[assoc_const("rect")] Rect { width: f32, height: f32 },
+//!     #[assoc_const("empty")] EmptySet,
+//!     #[assoc_const("type")] r#type,
+//! }
+//!
+//! assert!(Shape::Circle(1.0).is_circle());
+//! assert!(!Shape::Rect { width: 1.0, height: 2.0 }.is_circle());
+//! assert!(Shape::Rect { width: 1.0, height: 2.0 }.is_rect());
+//! assert!(Shape::EmptySet.is_empty_set());
+//! assert!(Shape::r#type.is_type());
+//! ```
+//!
+//! ### `must_use`
+//!
+//! Marks every generated method returning a value `#[must_use]`, for values that are a bug to
+//! ignore. `get_associated` is always `#[must_use]`, since the trait method is.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated)]
+//! #[associated(Type = &'static str, must_use)]
+//! enum Permission {
+//!     #[assoc_const("read")] Read,
+//!     #[assoc_const("write")] Write,
+//! }
+//!
+//! assert_eq!(Permission::Read.get_associated(), &"read");
+//! assert_eq!(Permission::Write.associated_len(), 5);
+//! ```
+//!
+//! ### `all_variants`
+//!
+//! Generates `fn all_variants() -> &'static [Self]`, listing every variant in declaration order.
+//! Requires an enum with only unit variants that implements `Copy`.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated, Clone, Copy, Debug, PartialEq)]
+//! #[associated(Type = u8, all_variants)]
+//! enum Level {
+//!     #[assoc_const(1)] Low,
+//!     #[assoc_const(5)] High,
+//! }
+//!
+//! assert_eq!(Level::all_variants(), [Level::Low, Level::High]);
+//! let total: u8 = Level::all_variants().iter().map(|l| l.get_associated()).sum();
+//! assert_eq!(total, 6);
+//! ```
+//!
+//! ### `unique_values`
+//!
+//! With the `alloc` feature of `associated`, generates `fn associated_unique_values() ->
+//! Vec<&'static Type>`, returning each distinct associated value once, in declaration order, such
+//! as for a menu of enums with synonymous variants. Requires `Type: PartialEq` and an enum with
+//! only unit variants.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated)]
+//! #[associated(Type = &'static str, unique_values)]
+//! enum Color {
+//!     #[assoc_const("black")] Black,
+//!     #[assoc_const("gray")] Gray,
+//!     #[assoc_const("gray")] Grey,
+//!     #[assoc_const("white")] White,
+//! }
+//!
+//! assert_eq!(Color::associated_unique_values(), [&"black", &"gray", &"white"]);
+//! ```
+//!
+//! ### `iter`
+//!
+//! Generates `fn iter() -> impl Iterator<Item = Self>`, yielding every variant in declaration order.
+//! Requires an enum with only unit variants.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated)]
+//! #[associated(Type = &'static str, iter)]
+//! enum Phonetic {
+//!     #[assoc_const("Alpha")] Alpha,
+//!     #[assoc_const("Bravo")] Bravo,
+//! }
+//!
+//! let names: Vec<_> = Phonetic::iter().map(|p| *p.get_associated()).collect();
+//! assert_eq!(names, ["Alpha", "Bravo"]);
+//! ```
+//!
+//! ### `sequence`
+//!
+//! Generates `const fn next_variant(&self) -> Option<Self>` and `const fn prev_variant(&self) ->
+//! Option<Self>`, stepping through the variants in declaration order and returning `None` past either
+//! end. With `sequence = cycle` they wrap around instead, so they return `Self`. Requires an enum with
+//! only unit variants.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated, Debug, PartialEq)]
+//! #[associated(Type = &'static str, sequence)]
+//! enum Stage {
+//!     #[assoc_const("draft")] Draft,
+//!     #[assoc_const("review")] Review,
+//!     #[assoc_const("published")] Published,
+//! }
+//!
+//! assert_eq!(Stage::Draft.next_variant(), Some(Stage::Review));
+//! assert_eq!(Stage::Published.next_variant(), None);
+//! assert_eq!(Stage::Review.prev_variant(), Some(Stage::Draft));
+//! assert_eq!(Stage::Draft.prev_variant(), None);
+//! ```
+//!
+//! ### `union`
+//!
+//! Generates `fn associated_union(variants: &[Self]) -> Type`, combining the associated values of
+//! `variants` with bitwise OR, e.g. to build a mask from a set of flags. Requires an integer `Type`.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated)]
+//! #[associated(Type = u32, union)]
+//! enum Permission {
+//!     #[assoc_const(0b001)] Read,
+//!     #[assoc_const(0b010)] Write,
+//!     #[assoc_const(0b100)] Execute,
+//! }
+//!
+//! use Permission::*;
+//! assert_eq!(Permission::associated_union(&[Read, Write, Execute]), 0b111);
+//! assert_eq!(Permission::associated_union(&[Read, Execute]), 0b101);
+//! assert_eq!(Permission::associated_union(&[]), 0);
+//! ```
+//!
+//! ### `unsafe_accessor`
+//!
+//! For `Type = *const T`, generates `unsafe fn get_associated_raw(&self) -> &'static T`,
+//! dereferencing the associated pointer. This keeps the `unsafe` acknowledgment for FFI tables in one
+//! place.
+//!
+//! Callers must ensure every associated pointer is non-null, aligned and points to a `T` that's valid
+//! for `'static` and never mutated.
+//!
+//! ```rust
+//! # use associated::Associated;
+//! #[derive(Associated)]
+//! #[associated(Type = *const u8, unsafe_accessor)]
+//! enum Symbol {
+//!     #[assoc_const(b"Alpha\0".as_ptr())] Alpha,
+//!     #[assoc_const(b"Bravo\0".as_ptr())] Bravo,
+//! }
+//!
+//! // SAFETY: every pointer above comes from a byte string literal.
+//! let first = unsafe { Symbol::Bravo.get_associated_raw() };
+//! assert_eq!(*first, b'B');
+//! ```
+
+use proc_macro::{self, TokenStream};
+use proc_macro2::{Group, Span, TokenStream as TokenStream2, TokenTree};
+use quote::{quote, quote_spanned, ToTokens};
+use syn::{
+    ext::IdentExt,
+    parse::{Error as ParseError, Parse, ParseStream, Result as ParseResult},
+    parse_macro_input, parse_quote,
+    punctuated::Punctuated,
+    spanned::Spanned,
+    token::Comma,
+    Attribute, DeriveInput, Expr, ExprLit, Fields, GenericArgument, Generics, Ident, ImplItem,
+    Lifetime, Lit, LitInt, LitStr, Meta, MetaNameValue, PathArguments, PathSegment, ReturnType,
+    Token, Type, Variant,
+};
+
+struct Args {
+    /// Every option given, so those that don't apply to the derive can be rejected.
+    given: Vec<Ident>,
+    /// The associated type, `&'static str` unless given as `Type = ...`.
+    assoc_type: Type,
+    /// Generate a `NAMES` const listing every associated string.
+    names: Option<Ident>,
+    /// Generate `impl From<Enum> for Type`.
+    into: Option<Ident>,
+    /// Generate `matches_associated_ignore_ascii_case`.
+    case_insensitive: Option<Ident>,
+    /// Generate `with_field_associated`, returning the field at this index alongside the value.
+    include_field: Option<LitInt>,
+    /// Generate a `<VARIANT>_ASSOCIATED` const for every `#[assoc_const]` variant.
+    expose_consts: Option<Ident>,
+    /// Fail compilation if two variants have equal associated values.
+    distinct: Option<Ident>,
+    /// Associate unannotated unit variants with their name.
+    default_name: Option<Ident>,
+    /// Generate `associated_contains`.
+    contains: Option<Ident>,
+    /// The type returned by `get_secondary`, which is only generated if given.
+    secondary_type: Option<Type>,
+    /// Generate `PartialOrd` and `Ord` comparing associated values.
+    ord_by_assoc: Option<Ident>,
+    /// Generate `Debug` formatting the associated value.
+    debug: Option<Ident>,
+    /// Generate `associated_at`, looking values up by discriminant.
+    associated_at: Option<Ident>,
+    /// Generate reverse lookups from associated values to variants.
+    from_associated: Option<Ident>,
+    /// Generate `const fn index_of_associated` for integer and `char` types.
+    index_of_associated: Option<Ident>,
+    /// Generate `resolve`, indexing the associated slice by the first tuple field.
+    resolve: Option<Ident>,
+    /// Generate `all_variants`, listing every unit variant.
+    all_variants: Option<Ident>,
+    /// Generate `associated_unique_values`, skipping repeated values.
+    unique_values: Option<Ident>,
+    /// Generate `get_associated_raw`, dereferencing an associated `*const T`.
+    unsafe_accessor: Option<Ident>,
+    /// Generate `iter`, yielding every unit variant.
+    iter: Option<Ident>,
+    /// Associate variants without an attribute with their doc comment.
+    from_doc: Option<Ident>,
+    /// Generate `associated_union`, ORing the associated values of several variants.
+    union: Option<Ident>,
+    /// Generate `from_associated_normalized`, ignoring whitespace and ASCII case.
+    from_str_loose: Option<Ident>,
+    /// Generate `PartialEq` between the enum and its associated type.
+    eq_value: Option<Ident>,
+    /// Generate `TryFrom<repr>` from discriminants.
+    try_from: Option<Ident>,
+    /// Generate `Display` padding associated strings to the longest one.
+    display_padded: Option<Ident>,
+    /// Generate `PAIRS`, discriminants and values sorted by discriminant.
+    pairs: Option<Ident>,
+    /// The type of the ids given with `#[assoc_pair]`, returned by `id`.
+    id: Option<Type>,
+    /// Generate an `is_<variant>` method for every variant.
+    predicates: Option<Ident>,
+    /// The casing applied to default names, which it enables, as with serde's `rename_all`.
+    rename_all: Option<(Ident, LitStr)>,
+    /// Generate `next_variant` and `prev_variant`, wrapping around with `sequence = cycle`.
+    sequence: Option<(Ident, Option<Ident>)>,
+    /// A const fn computing the value of every variant from the variant itself.
+    via: Option<(Ident, syn::Path)>,
+    /// Mark `get_associated` and the generated accessors `#[must_use]`.
+    must_use: Option<Ident>,
+    /// Prepended to default names after `rename_all`, which it enables.
+    prefix: Option<(Ident, LitStr)>,
+    /// Appended to default names after `rename_all`, which it enables.
+    suffix: Option<(Ident, LitStr)>,
+    /// Generate `const fn associated_const`, and `associated_eq` for integer types.
+    const_fn: Option<Ident>,
+    /// The type `AssociatedWith` borrows values from.
+    context: Option<Type>,
+    /// Bind the fields of every variant for its `#[assoc]` expression to use.
+    bind_fields: Option<Ident>,
+    /// Generate `clap::ValueEnum` with the associated strings as value names.
+    value_enum: Option<Ident>,
+    /// Generate `FromStr` from associated strings, erroring with the valid ones.
+    from_str: Option<Ident>,
+    /// Check every associated string is valid, with `validate = ident`.
+    validate: Option<(Ident, Ident)>,
+}
+
+enum AssocKind {
+    Constant,
+    Static,
+    Lazy,
+}
+
+struct Assoc<'a> {
+    kind: AssocKind,
+    attr: &'a Attribute,
+    /// Whether the argument is a string literal to uppercase, from `#[assoc_upper]`.
+    uppercase: bool,
+}
+
+impl Parse for Args {
+    fn parse(input: ParseStream) -> ParseResult<Self> {
+        let mut assoc_type = None;
+        let mut args = Args {
+            given: Vec::new(),
+            assoc_type: parse_quote!(&'static str),
+            names: None,
+            into: None,
+            case_insensitive: None,
+            include_field: None,
+            expose_consts: None,
+            distinct: None,
+            default_name: None,
+            contains: None,
+            secondary_type: None,
+            ord_by_assoc: None,
+            debug: None,
+            associated_at: None,
+            from_associated: None,
+            index_of_associated: None,
+            resolve: None,
+            all_variants: None,
+            unique_values: None,
+            unsafe_accessor: None,
+            iter: None,
+            from_doc: None,
+            union: None,
+            from_str_loose: None,
+            eq_value: None,
+            try_from: None,
+            display_padded: None,
+            pairs: None,
+            id: None,
+            predicates: None,
+            rename_all: None,
+            sequence: None,
+            via: None,
+            must_use: None,
+            prefix: None,
+            suffix: None,
+            const_fn: None,
+            context: None,
+            bind_fields: None,
+            value_enum: None,
+            from_str: None,
+            validate: None,
+        };
+        while !input.is_empty() {
+            let option = input.parse::<Ident>()?;
+            args.given.push(option.clone());
+            match option.to_string().as_str() {
+                "Type" => {
+                    input.parse::<Token![=]>()?;
+                    set_option(&mut assoc_type, &option, input.parse()?)?
+                }
+                "SecondaryType" => {
+                    input.parse::<Token![=]>()?;
+                    set_option(&mut args.secondary_type, &option, input.parse()?)?
+                }
+                "Context" => {
+                    input.parse::<Token![=]>()?;
+                    set_option(&mut args.context, &option, input.parse()?)?
+                }
+                "via" => {
+                    input.parse::<Token![=]>()?;
+                    set_option(&mut args.via, &option, (option.clone(), input.parse()?))?
+                }
+                "id" => {
+                    input.parse::<Token![=]>()?;
+                    set_option(&mut args.id, &option, input.parse()?)?
+                }
+                "rename_all" => {
+                    input.parse::<Token![=]>()?;
+                    let casing = input.parse::<LitStr>()?;
+                    if !CASINGS.contains(&casing.value().as_str()) {
+                        return Err(ParseError::new(
+                            casing.span(),
+                            format!(
+                                "Unknown casing `{}`, expected one of {}",
+                                casing.value(),
+                                CASINGS.map(|c| format!("`{}`", c)).join(", ")
+                            ),
+                        ));
+                    }
+                    set_option(&mut args.rename_all, &option, (option.clone(), casing))?
+                }
+                "prefix" => {
+                    input.parse::<Token![=]>()?;
+                    set_option(&mut args.prefix, &option, (option.clone(), input.parse()?))?
+                }
+                "suffix" => {
+                    input.parse::<Token![=]>()?;
+                    set_option(&mut args.suffix, &option, (option.clone(), input.parse()?))?
+                }
+                "validate" => {
+                    input.parse::<Token![=]>()?;
+                    let validation = input.parse::<Ident>()?;
+                    if validation != "ident" {
+                        return Err(ParseError::new(
+                            validation.span(),
+                            format!("Unknown validation `{}`, expected `ident`", validation),
+                        ));
+                    }
+                    set_option(&mut args.validate, &option, (option.clone(), validation))?
+                }
+                "sequence" => {
+                    let mode = if input.peek(Token![=]) {
+                        input.parse::<Token![=]>()?;
+                        let mode = input.parse::<Ident>()?;
+                        if mode != "cycle" {
+                            return Err(ParseError::new(
+                                mode.span(),
+                                format!("Unknown sequence mode `{}`, expected `cycle`", mode),
+                            ));
+                        }
+                        Some(mode)
+                    } else {
+                        None
+                    };
+                    set_option(&mut args.sequence, &option, (option.clone(), mode))?
+                }
+                "names" => set_option(&mut args.names, &option, option.clone())?,
+                "into" => set_option(&mut args.into, &option, option.clone())?,
+                "case_insensitive" => {
+                    set_option(&mut args.case_insensitive, &option, option.clone())?
+                }
+                "expose_consts" => set_option(&mut args.expose_consts, &option, option.clone())?,
+                "contains" => set_option(&mut args.contains, &option, option.clone())?,
+                "debug" => set_option(&mut args.debug, &option, option.clone())?,
+                "associated_at" => set_option(&mut args.associated_at, &option, option.clone())?,
+                "default_name" => set_option(&mut args.default_name, &option, option.clone())?,
+                "distinct" => set_option(&mut args.distinct, &option, option.clone())?,
+                "ord_by_assoc" => set_option(&mut args.ord_by_assoc, &option, option.clone())?,
+                "from_associated" => {
+                    set_option(&mut args.from_associated, &option, option.clone())?
+                }
+                "index_of_associated" => {
+                    set_option(&mut args.index_of_associated, &option, option.clone())?
+                }
+                "resolve" => set_option(&mut args.resolve, &option, option.clone())?,
+                "all_variants" => set_option(&mut args.all_variants, &option, option.clone())?,
+                "unique_values" => set_option(&mut args.unique_values, &option, option.clone())?,
+                "iter" => set_option(&mut args.iter, &option, option.clone())?,
+                "from_doc" => set_option(&mut args.from_doc, &option, option.clone())?,
+                "union" => set_option(&mut args.union, &option, option.clone())?,
+                "eq_value" => set_option(&mut args.eq_value, &option, option.clone())?,
+                "from_str" => set_option(&mut args.from_str, &option, option.clone())?,
+                "value_enum" => set_option(&mut args.value_enum, &option, option.clone())?,
+                "bind_fields" => set_option(&mut args.bind_fields, &option, option.clone())?,
+                "const_fn" => set_option(&mut args.const_fn, &option, option.clone())?,
+                "must_use" => set_option(&mut args.must_use, &option, option.clone())?,
+                "predicates" => set_option(&mut args.predicates, &option, option.clone())?,
+                "pairs" => set_option(&mut args.pairs, &option, option.clone())?,
+                "try_from" => set_option(&mut args.try_from, &option, option.clone())?,
+                "display_padded" => set_option(&mut args.display_padded, &option, option.clone())?,
+                "from_str_loose" => set_option(&mut args.from_str_loose, &option, option.clone())?,
+                "unsafe_accessor" => {
+                    set_option(&mut args.unsafe_accessor, &option, option.clone())?
+                }
+                "include_field" => {
+                    input.parse::<Token![=]>()?;
+                    let index = input.parse::<LitInt>()?;
+                    index.base10_parse::<usize>()?;
+                    set_option(&mut args.include_field, &option, index)?
+                }
+                _ => {
+                    return Err(ParseError::new(
+                        option.span(),
+                        format!("Unknown option `{}`", option),
+                    ))
+                }
+            }
+            if input.is_empty() {
+                break;
+            }
+            input.parse::<Comma>()?;
+        }
+        if let Some(assoc_type) = assoc_type {
+            args.assoc_type = assoc_type;
+        }
+        Ok(args)
+    }
+}
+
+/// Stores the value given for `option`, erroring if the option was already given.
+fn set_option<T>(slot: &mut Option<T>, option: &Ident, value: T) -> ParseResult<()> {
+    if slot.is_some() {
+        return Err(ParseError::new(
+            option.span(),
+            format!("Duplicate option `{}`", option),
+        ));
+    }
+    *slot = Some(value);
+    Ok(())
+}
+
+/// Strips any invisible groups or parentheses around `ty`.
+fn ungroup(ty: &Type) -> &Type {
+    match ty {
+        Type::Group(g) => ungroup(&g.elem),
+        Type::Paren(p) => ungroup(&p.elem),
+        _ => ty,
+    }
+}
+
+/// Returns the last segment of `ty` if it's a path without a qualified self.
+fn last_segment(ty: &Type) -> Option<&PathSegment> {
+    match ungroup(ty) {
+        Type::Path(p) if p.qself.is_none() => p.path.segments.last(),
+        _ => None,
+    }
+}
+
+/// Returns whether `lifetime` is `'static`.
+fn is_static_lifetime(lifetime: Option<&Lifetime>) -> bool {
+    lifetime.is_some_and(|l| l.ident == "static")
+}
+
+/// Returns whether `ty` is `&'static str`, the only type string-specific options accept.
+fn is_static_str(ty: &Type) -> bool {
+    match ungroup(ty) {
+        Type::Reference(r) => {
+            r.mutability.is_none()
+                && is_static_lifetime(r.lifetime.as_ref())
+                && matches!(ungroup(&r.elem), Type::Path(p) if p.qself.is_none() && p.path.is_ident("str"))
+        }
+        _ => false,
+    }
+}
+
+/// The primitive integer types.
+const INTEGERS: [&str; 12] = [
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+];
+
+/// Returns whether `ty` is a primitive integer type.
+fn is_integer(ty: &Type) -> bool {
+    match ungroup(ty) {
+        Type::Path(p) if p.qself.is_none() => INTEGERS.iter().any(|i| p.path.is_ident(i)),
+        _ => false,
+    }
+}
+
+/// Returns whether `ty` is `char`.
+fn is_char(ty: &Type) -> bool {
+    matches!(ungroup(ty), Type::Path(p) if p.qself.is_none() && p.path.is_ident("char"))
+}
+
+/// Returns the element type of `ty` if it's `&'static [T]`.
+fn static_slice_element(ty: &Type) -> Option<&Type> {
+    match ungroup(ty) {
+        Type::Reference(r) if r.mutability.is_none() && is_static_lifetime(r.lifetime.as_ref()) => {
+            match ungroup(&r.elem) {
+                Type::Slice(slice) => Some(&slice.elem),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Returns `N` if `ty` is `&'static [T; N]`.
+fn static_array_len(ty: &Type) -> Option<&Expr> {
+    match ungroup(ty) {
+        Type::Reference(r) if r.mutability.is_none() && is_static_lifetime(r.lifetime.as_ref()) => {
+            match ungroup(&r.elem) {
+                Type::Array(array) => Some(&array.len),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Returns the pointee type of `ty` if it's `*const T`.
+fn const_pointer_element(ty: &Type) -> Option<&Type> {
+    match ungroup(ty) {
+        Type::Ptr(pointer) if pointer.const_token.is_some() => Some(&pointer.elem),
+        _ => None,
+    }
+}
+
+/// Returns the index type of `ty` if it's `Range<Idx>` or `RangeInclusive<Idx>`.
+fn range_index(ty: &Type) -> Option<&Type> {
+    let segment = match last_segment(ty) {
+        Some(segment) if segment.ident == "Range" || segment.ident == "RangeInclusive" => segment,
+        _ => return None,
+    };
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            GenericArgument::Type(index) => Some(index),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns the type argument of `ty` if it's `Option<T>`.
+fn option_argument(ty: &Type) -> Option<&Type> {
+    let segment = match last_segment(ty) {
+        Some(segment) if segment.ident == "Option" => segment,
+        _ => return None,
+    };
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            GenericArgument::Type(argument) => Some(argument),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns whether `ty` is `Cow<'static, str>`.
+fn is_cow_str(ty: &Type) -> bool {
+    let segment = match last_segment(ty) {
+        Some(segment) if segment.ident == "Cow" => segment,
+        _ => return false,
+    };
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) => {
+            let args: Vec<_> = args.args.iter().collect();
+            matches!(
+                args.as_slice(),
+                [GenericArgument::Lifetime(l), GenericArgument::Type(Type::Path(p))]
+                    if l.ident == "static" && p.qself.is_none() && p.path.is_ident("str")
+            )
+        }
+        _ => false,
+    }
+}
+
+/// Returns whether `ty` is `&'static CStr`.
+fn is_static_cstr(ty: &Type) -> bool {
+    match ungroup(ty) {
+        Type::Reference(r) => {
+            r.mutability.is_none()
+                && is_static_lifetime(r.lifetime.as_ref())
+                && last_segment(&r.elem).is_some_and(|segment| {
+                    segment.ident == "CStr" && matches!(segment.arguments, PathArguments::None)
+                })
+        }
+        _ => false,
+    }
+}
+
+/// Returns whether `ty` is `&'static Path`, as in `std::path::Path`.
+fn is_static_path(ty: &Type) -> bool {
+    match ungroup(ty) {
+        Type::Reference(r) => {
+            r.mutability.is_none()
+                && is_static_lifetime(r.lifetime.as_ref())
+                && is_std_type(&r.elem, "path", "Path")
+        }
+        _ => false,
+    }
+}
+
+/// Returns whether `ty` is `Ordering`, as in `core::cmp::Ordering`.
+fn is_ordering(ty: &Type) -> bool {
+    is_std_type(ty, "cmp", "Ordering")
+}
+
+/// Returns whether `ty` is the standard type `name` from `module`, written as `name`, `module::name`,
+/// or a full path through `std`, `core` or `alloc`. Other paths ending in `name` are user types.
+fn is_std_type(ty: &Type, module: &str, name: &str) -> bool {
+    let path = match ungroup(ty) {
+        Type::Path(p) if p.qself.is_none() => &p.path,
+        _ => return false,
+    };
+    if path
+        .segments
+        .iter()
+        .any(|segment| !matches!(segment.arguments, PathArguments::None))
+    {
+        return false;
+    }
+    let segments: Vec<_> = path.segments.iter().map(|segment| &segment.ident).collect();
+    match segments.as_slice() {
+        [ty] => path.leading_colon.is_none() && *ty == name,
+        [m, ty] => path.leading_colon.is_none() && *m == module && *ty == name,
+        [krate, m, ty] => {
+            ["std", "core", "alloc"].iter().any(|k| *krate == k) && *m == module && *ty == name
+        }
+        _ => false,
+    }
+}
+
+/// Errors at `option` unless `ty` is `&'static str`.
+fn require_static_str(ty: &Type, option: &Ident) -> ParseResult<()> {
+    if is_static_str(ty) {
+        Ok(())
+    } else {
+        Err(ParseError::new(
+            option.span(),
+            format!("`{}` requires `Type = &'static str`", option),
+        ))
+    }
+}
+
+/// Errors at `option` unless every variant of the enum is a unit variant.
+fn require_unit_variants(
+    variants: &Punctuated<Variant, Comma>,
+    enum_ident: &Ident,
+    option: &Ident,
+) -> ParseResult<()> {
+    match variants.iter().find(|v| !matches!(v.fields, Fields::Unit)) {
+        Some(v) => Err(ParseError::new(
+            option.span(),
+            format!(
+                "Cannot derive `Associated` for `{}`: `{}` requires unit variants, but `{}` has fields",
+                enum_ident, option, v.ident
+            ),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Generates a const expression evaluating to each variant's associated value, in declaration order.
+///
+/// `#[assoc_lazy]` values are computed at runtime, so they're rejected for `option`.
+fn generate_const_values(
+    associated_variants: &[(&Ident, &Fields, Expr, AssocKind)],
+    option: &Ident,
+) -> ParseResult<Vec<TokenStream2>> {
+    associated_variants
+        .iter()
+        .map(|(_, _, expr, kind)| match kind {
+            AssocKind::Constant => Ok(quote! { #expr }),
+            AssocKind::Static => Ok(quote! { *#expr }),
+            AssocKind::Lazy => Err(ParseError::new(
+                expr.span(),
+                format!(
+                    "`{}` requires const values, but `assoc_lazy` values are computed at runtime",
+                    option
+                ),
+            )),
+        })
+        .collect()
+}
+
+/// Generates the inherent items requested through options in `#[associated]`.
+fn generate_inherent_items(
+    enum_ident: &Ident,
+    generics: &Generics,
+    variants: &Punctuated<Variant, Comma>,
+    args: &Args,
+    associated_variants: &[(&Ident, &Fields, Expr, AssocKind)],
+) -> ParseResult<TokenStream2> {
+    let mut items = generate_debug_associated(enum_ident, variants, &args.assoc_type);
+    if let Some(option) = &args.names {
+        require_static_str(&args.assoc_type, option)?;
+        require_unit_variants(variants, enum_ident, option)?;
+        let values = generate_const_values(associated_variants, option)?;
+        items.extend(quote! {
+            /// The associated string of every variant, in declaration order.
+            pub const NAMES: &'static [&'static str] = &[#(#values),*];
+        });
+    }
+    if args.expose_consts.is_some() {
+        items.extend(generate_exposed_consts(
+            enum_ident,
+            &args.assoc_type,
+            associated_variants,
+        )?);
+    }
+    if let Some(option) = &args.associated_at {
+        // Its `const` array can't use const generics, which values of such enums might.
+        if generics.const_params().next().is_some() {
+            return Err(ParseError::new(
+                option.span(),
+                format!("`{}` can't be used with const generics", option),
+            ));
+        }
+        require_unit_variants(variants, enum_ident, option)?;
+        items.extend(generate_associated_at(
+            variants,
+            &args.assoc_type,
+            associated_variants,
+            option,
+        )?);
+    }
+    if let Some(option) = &args.const_fn {
+        let associated_type = &args.assoc_type;
+        // Returning a referenced value by value would need `Type: Copy`.
+        if let Some((_, _, expr, _)) = associated_variants
+            .iter()
+            .find(|(.., kind)| !matches!(kind, AssocKind::Constant))
+        {
+            return Err(ParseError::new(
+                expr.span(),
+                format!("`{}` requires `assoc_const` values", option),
+            ));
+        }
+        let match_block =
+            generate_const_match_body(enum_ident, associated_type, associated_variants);
+        items.extend(quote! {
+            /// Returns this variant's associated constant by value, in const contexts.
+            pub const fn associated_const(&self) -> #associated_type {
+                match self {
+                    #match_block
+                }
+            }
+        });
+        if is_integer(associated_type) {
+            items.extend(quote! {
+                /// Returns whether this variant's associated constant equals `other`, in const
+                /// contexts.
+                pub const fn associated_eq(&self, other: #associated_type) -> bool {
+                    self.associated_const() == other
+                }
+            });
+        }
+    }
+    if let Some((option, mode)) = &args.sequence {
+        require_unit_variants(variants, enum_ident, option)?;
+        items.extend(generate_sequence(enum_ident, variants, mode.is_some()));
+    }
+    if args.predicates.is_some() {
+        items.extend(generate_predicates(enum_ident, variants)?);
+    }
+    if let Some(index) = &args.include_field {
+        items.extend(generate_with_field_associated(enum_ident, variants, index)?);
+    }
+    if let Some(option) = &args.case_insensitive {
+        require_static_str(&args.assoc_type, option)?;
+        items.extend(quote! {
+            /// Returns whether `s` equals this variant's associated string, ignoring ASCII case.
+            pub fn matches_associated_ignore_ascii_case(&self, s: &str) -> bool {
+                associated::Associated::get_associated(self).eq_ignore_ascii_case(s)
+            }
+        });
+    }
+    if let Some(id_type) = &args.id {
+        let mut arms = TokenStream2::new();
+        for v in variants.iter() {
+            let (_, id) = parse_pair(v, enum_ident, "Associated")?;
+            let variant_ident = &v.ident;
+            let pattern = variant_pattern(&v.fields);
+            arms.extend(quote! {
+                #enum_ident::#variant_ident #pattern => {
+                    const ID: #id_type = #id;
+                    ID
+                },
+            });
+        }
+        items.extend(quote! {
+            /// Returns the id paired with this variant's associated constant.
+            pub const fn id(&self) -> #id_type {
+                match self {
+                    #arms
+                }
+            }
+        });
+    }
+    if let Some(secondary_type) = &args.secondary_type {
+        let secondary_variants =
+            parse_associated_values(variants, enum_ident, &ASSOC_SECONDARY, None, "Associated")?;
+        let match_block = generate_match_body(
+            enum_ident,
+            secondary_type,
+            &secondary_variants,
+            false,
+            false,
+        );
+        items.extend(quote! {
+            /// Returns a static lifetime reference to the secondary constant associated with this variant.
+            pub fn get_secondary(&self) -> &'static #secondary_type {
+                match self {
+                    #match_block
+                }
+            }
+        });
+    }
+    if let Some(option) = &args.from_associated {
+        require_unit_variants(variants, enum_ident, option)?;
+        items.extend(generate_from_associated(
+            &args.assoc_type,
+            associated_variants,
+        ));
+    }
+    if let Some(option) = &args.index_of_associated {
+        let ty = &args.assoc_type;
+        if !is_integer(ty) && !is_char(ty) {
+            return Err(ParseError::new(
+                option.span(),
+                "`index_of_associated` requires an integer or `char` `Type`",
+            ));
+        }
+        let values = generate_const_values(associated_variants, option)?;
+        let consts =
+            (0..values.len()).map(|i| Ident::new(&format!("VALUE_{}", i), Span::call_site()));
+        let patterns = consts.clone();
+        let indices = 0..values.len();
+        items.extend(quote! {
+            /// Returns the declaration index of the first variant associated with `value`, if any,
+            /// in const contexts.
+            #[allow(unreachable_patterns)]
+            pub const fn index_of_associated(value: #ty) -> ::core::option::Option<usize> {
+                #(const #consts: #ty = #values;)*
+                match value {
+                    #(#patterns => ::core::option::Option::Some(#indices),)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+        });
+    }
+    if let Some((option, _)) = &args.validate {
+        require_static_str(&args.assoc_type, option)?;
+        for (_, _, expr, kind) in associated_variants {
+            let literal = str_literal(expr, kind).ok_or_else(|| {
+                ParseError::new(
+                    expr.span(),
+                    format!("`{}` requires string literal values", option),
+                )
+            })?;
+            // Parsing alone would accept surrounding whitespace and comments.
+            let value = literal.value();
+            if syn::parse_str::<Ident>(&value).map_or(true, |ident| ident != value) {
+                return Err(ParseError::new(
+                    literal.span(),
+                    format!("{:?} is not a valid identifier", value),
+                ));
+            }
+        }
+    }
+    if let Some(option) = &args.from_str_loose {
+        require_static_str(&args.assoc_type, option)?;
+        require_unit_variants(variants, enum_ident, option)?;
+        let variant_idents = associated_variants.iter().map(|(i, ..)| *i);
+        items.extend(quote! {
+            /// Returns the first variant whose associated string equals `s`, ignoring surrounding
+            /// whitespace and ASCII case.
+            pub fn from_associated_normalized(s: &str) -> ::core::option::Option<Self> {
+                let s = s.trim();
+                #(
+                    if associated::Associated::get_associated(&Self::#variant_idents).trim().eq_ignore_ascii_case(s) {
+                        return ::core::option::Option::Some(Self::#variant_idents);
+                    }
+                )*
+                ::core::option::Option::None
+            }
+        });
+    }
+    if let Some(option) = &args.contains {
+        items.extend(generate_associated_contains(&args.assoc_type, option)?);
+    }
+    if let Some(option) = &args.all_variants {
+        require_unit_variants(variants, enum_ident, option)?;
+        let variant_idents = variants.iter().map(|v| &v.ident);
+        // Spanned at the option so a missing `Copy` is reported there.
+        let bound = quote_spanned! {option.span()=> Self: ::core::marker::Copy };
+        items.extend(quote! {
+            /// Every variant of this enum, in declaration order.
+            pub fn all_variants() -> &'static [Self]
+            where
+                #bound,
+            {
+                &[#(#enum_ident::#variant_idents),*]
+            }
+        });
+    }
+    if let Some(option) = &args.unique_values {
+        require_unit_variants(variants, enum_ident, option)?;
+        let associated_type = &args.assoc_type;
+        let variant_idents = variants.iter().map(|v| &v.ident);
+        let bound = quote_spanned! {option.span()=> #associated_type: ::core::cmp::PartialEq };
+        items.extend(quote! {
+            associated::__private::if_alloc! {
+                /// Returns each distinct associated value once, in declaration order.
+                pub fn associated_unique_values() -> associated::__private::Vec<&'static #associated_type>
+                where
+                    #bound,
+                {
+                    let mut values = associated::__private::Vec::new();
+                    #(
+                        let value = associated::Associated::get_associated(&#enum_ident::#variant_idents);
+                        if !values.contains(&value) {
+                            values.push(value);
+                        }
+                    )*
+                    values
+                }
+            }
+        });
+    }
+    if let Some(option) = &args.iter {
+        require_unit_variants(variants, enum_ident, option)?;
+        let variant_idents = variants.iter().map(|v| &v.ident);
+        items.extend(quote! {
+            /// Returns an iterator over every variant of this enum, in declaration order.
+            pub fn iter() -> impl ::core::iter::Iterator<Item = Self> {
+                [#(#enum_ident::#variant_idents),*].into_iter()
+            }
+        });
+    }
+    if let Some(option) = &args.union {
+        let ty = &args.assoc_type;
+        if !is_integer(ty) {
+            return Err(ParseError::new(
+                option.span(),
+                "`union` requires an integer `Type`",
+            ));
+        }
+        items.extend(quote! {
+            /// Returns the bitwise OR of the values associated with `variants`.
+            pub fn associated_union(variants: &[Self]) -> #ty {
+                variants
+                    .iter()
+                    .fold(0, |union, variant| union | *associated::Associated::get_associated(variant))
+            }
+        });
+    }
+    if let Some(option) = &args.unsafe_accessor {
+        let pointee = const_pointer_element(&args.assoc_type).ok_or_else(|| {
+            ParseError::new(
+                option.span(),
+                "`unsafe_accessor` requires `Type = *const T`",
+            )
+        })?;
+        items.extend(quote! {
+            /// Dereferences the pointer associated with this variant.
+            ///
+            /// # Safety
+            ///
+            /// The associated pointer must be non-null, aligned and point to a value that's valid for
+            /// `'static` and never mutated.
+            pub unsafe fn get_associated_raw(&self) -> &'static #pointee {
+                let pointer = *associated::Associated::get_associated(self);
+                // SAFETY: upheld by the caller.
+                unsafe { &*pointer }
+            }
+        });
+    }
+    if let Some(option) = &args.pairs {
+        require_unit_variants(variants, enum_ident, option)?;
+        items.extend(generate_pairs(
+            enum_ident,
+            &args.assoc_type,
+            associated_variants,
+            option,
+        )?);
+    }
+    if let Some(option) = &args.resolve {
+        items.extend(generate_resolve(
+            enum_ident,
+            variants,
+            &args.assoc_type,
+            option,
+        )?);
+    }
+    items.extend(generate_type_methods(
+        enum_ident,
+        &args.assoc_type,
+        associated_variants,
+    ));
+    if args.must_use.is_some() {
+        items = mark_must_use(items)?;
+    }
+    Ok(items)
+}
+
+/// Adds `#[must_use]` to every method among `items` that returns a value, including those wrapped in
+/// `if_alloc!`.
+fn mark_must_use(items: TokenStream2) -> ParseResult<TokenStream2> {
+    let items = syn::parse::Parser::parse2(
+        |input: ParseStream| {
+            let mut items = Vec::new();
+            while !input.is_empty() {
+                items.push(input.parse::<ImplItem>()?);
+            }
+            Ok(items)
+        },
+        items,
+    )?;
+    items
+        .into_iter()
+        .map(|mut item| {
+            match &mut item {
+                ImplItem::Method(method) if !matches!(method.sig.output, ReturnType::Default) => {
+                    method.attrs.push(parse_quote!(#[must_use]));
+                }
+                ImplItem::Macro(item)
+                    if item
+                        .mac
+                        .path
+                        .segments
+                        .last()
+                        .is_some_and(|segment| segment.ident == "if_alloc") =>
+                {
+                    item.mac.tokens = mark_must_use(item.mac.tokens.clone())?;
+                }
+                _ => {}
+            }
+            Ok(quote! { #item })
+        })
+        .collect()
+}
+
+/// Generates reverse lookups from associated values to unit variants.
+///
+/// When an associated value is shared, the first variant declared with it is returned.
+fn generate_from_associated(
+    associated_type: &Type,
+    associated_variants: &[(&Ident, &Fields, Expr, AssocKind)],
+) -> TokenStream2 {
+    let variant_idents: Vec<_> = associated_variants.iter().map(|(i, ..)| *i).collect();
+    let literals: Option<Vec<_>> = associated_variants
+        .iter()
+        .map(|(_, _, expr, kind)| str_literal(expr, kind))
+        .collect();
+    let mut lookups = match literals.filter(|_| is_static_str(associated_type)) {
+        Some(literals) => {
+            // Stable, so each string keeps only its first declared variant after the dedup.
+            let mut sorted: Vec<_> = literals.iter().zip(&variant_idents).collect();
+            sorted.sort_by_key(|(lit, _)| lit.value());
+            sorted.dedup_by(|(a, _), (b, _)| a.value() == b.value());
+            let (sorted_literals, sorted_idents): (Vec<&LitStr>, Vec<&&Ident>) =
+                sorted.into_iter().unzip();
+            quote! {
+                /// Every associated string paired with its first variant, sorted by string.
+                pub const SORTED: &'static [(&'static str, Self)] =
+                    &[#((#sorted_literals, Self::#sorted_idents)),*];
+
+                /// Returns the first variant associated with `value`, if any.
+                pub fn from_associated(value: &#associated_type) -> ::core::option::Option<Self> {
+                    let index = Self::SORTED.binary_search_by(|(s, _)| (*s).cmp(*value)).ok()?;
+                    match &Self::SORTED[index].1 {
+                        #(Self::#variant_idents => ::core::option::Option::Some(Self::#variant_idents),)*
+                    }
+                }
+            }
+        }
+        None => quote! {
+            /// Returns the first variant associated with `value`, if any.
+            pub fn from_associated(value: &#associated_type) -> ::core::option::Option<Self> {
+                #(
+                    if associated::Associated::get_associated(&Self::#variant_idents) == value {
+                        return ::core::option::Option::Some(Self::#variant_idents);
+                    }
+                )*
+                ::core::option::Option::None
+            }
+        },
+    };
+    lookups.extend(quote! {
+
+        associated::__private::if_alloc! {
+            /// Returns every variant associated with `value`, in declaration order.
+            pub fn all_from_associated(value: &#associated_type) -> associated::__private::Vec<Self> {
+                let mut variants = associated::__private::Vec::new();
+                #(
+                    if associated::Associated::get_associated(&Self::#variant_idents) == value {
+                        variants.push(Self::#variant_idents);
+                    }
+                )*
+                variants
+            }
+        }
+    });
+    let all_const = associated_variants
+        .iter()
+        .all(|(_, _, _, kind)| matches!(kind, AssocKind::Constant));
+    if static_slice_element(associated_type).is_some_and(is_static_str) {
+        lookups.extend(quote! {
+            /// Returns the first variant whose associated slice contains `s`, if any.
+            pub fn from_any_associated(s: &str) -> ::core::option::Option<Self> {
+                #(
+                    if associated::Associated::get_associated(&Self::#variant_idents).contains(&s) {
+                        return ::core::option::Option::Some(Self::#variant_idents);
+                    }
+                )*
+                ::core::option::Option::None
+            }
+        });
+    }
+    if is_integer(associated_type) && all_const {
+        let values = associated_variants.iter().map(|(_, _, expr, _)| expr);
+        let consts: Vec<_> = (0..values.len())
+            .map(|i| Ident::new(&format!("VALUE_{}", i), Span::call_site()))
+            .collect();
+        lookups.extend(quote! {
+            /// Returns the first variant associated with `value`, if any, in const contexts.
+            #[allow(unreachable_patterns)]
+            pub const fn from_associated_const(value: #associated_type) -> ::core::option::Option<Self> {
+                #(const #consts: #associated_type = #values;)*
+                match value {
+                    #(#consts => ::core::option::Option::Some(Self::#variant_idents),)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+        });
+    }
+    lookups
+}
+
+/// Returns the string literal of a value given as `#[assoc_const("...")]` or `#[assoc(&"...")]`.
+fn str_literal(expr: &Expr, kind: &AssocKind) -> Option<LitStr> {
+    let expr = match (kind, expr) {
+        (AssocKind::Constant, expr) => expr,
+        (AssocKind::Static, Expr::Reference(reference)) => &reference.expr,
+        _ => return None,
+    };
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(lit), ..
+        }) => Some(lit.clone()),
+        _ => None,
+    }
+}
+
+/// Generates `associated_contains` for slice and range associations.
+fn generate_associated_contains(
+    associated_type: &Type,
+    option: &Ident,
+) -> ParseResult<TokenStream2> {
+    if let Some(index) = range_index(associated_type) {
+        return Ok(quote! {
+            /// Returns whether this variant's associated range contains `value`.
+            pub fn associated_contains(&self, value: #index) -> bool {
+                associated::Associated::get_associated(self).contains(&value)
+            }
+        });
+    }
+    let element = match static_slice_element(associated_type) {
+        Some(element) => element,
+        None => {
+            return Err(ParseError::new(
+                option.span(),
+                "`contains` requires `Type = &'static [T]`, `Range<T>` or `RangeInclusive<T>`",
+            ))
+        }
+    };
+    Ok(if is_static_str(element) {
+        quote! {
+            /// Returns whether this variant's associated slice contains `s`.
+            pub fn associated_contains(&self, s: &str) -> bool {
+                associated::Associated::get_associated(self).iter().any(|element| *element == s)
+            }
+        }
+    } else {
+        quote! {
+            /// Returns whether this variant's associated slice contains `value`.
+            pub fn associated_contains(&self, value: &#element) -> bool {
+                associated::Associated::get_associated(self).contains(value)
+            }
+        }
+    })
+}
+
+/// Generates the inherent methods specific to the associated type, which don't need an option.
+fn generate_type_methods(
+    enum_ident: &Ident,
+    associated_type: &Type,
+    associated_variants: &[(&Ident, &Fields, Expr, AssocKind)],
+) -> TokenStream2 {
+    let mut methods = TokenStream2::new();
+    // `#[assoc]` expressions needn't be const, so const methods need every value to be const.
+    let all_const = associated_variants
+        .iter()
+        .all(|(_, _, _, kind)| matches!(kind, AssocKind::Constant));
+    if is_static_str(associated_type) {
+        methods.extend(quote! {
+            /// Returns the bytes of this variant's associated string.
+            pub fn associated_bytes(&self) -> &'static [u8] {
+                associated::Associated::get_associated(self).as_bytes()
+            }
+        });
+    }
+    if is_static_str(associated_type) && all_const {
+        let match_block =
+            generate_const_match_body(enum_ident, associated_type, associated_variants);
+        methods.extend(quote! {
+            /// Returns the length in bytes of this variant's associated string.
+            pub const fn associated_len(&self) -> usize {
+                let associated: &'static str = match self {
+                    #match_block
+                };
+                associated.len()
+            }
+        });
+    }
+    if let Some(element) = static_slice_element(associated_type) {
+        methods.extend(quote! {
+            /// Returns an iterator over this variant's associated slice.
+            pub fn associated_iter(&self) -> ::core::slice::Iter<'static, #element> {
+                associated::Associated::get_associated(self).iter()
+            }
+        });
+    }
+    if let Some(len) = static_array_len(associated_type) {
+        methods.extend(quote! {
+            /// Returns the length of this variant's associated array.
+            pub const fn associated_len(&self) -> usize {
+                #len
+            }
+        });
+    }
+    if option_argument(associated_type).is_some_and(is_static_str) {
+        methods.extend(quote! {
+            /// Returns this variant's associated string, or `""` if it has none.
+            pub fn associated_or_empty(&self) -> &'static str {
+                associated::Associated::get_associated(self).unwrap_or("")
+            }
+        });
+    }
+    if is_cow_str(associated_type) {
+        methods.extend(quote! {
+            /// Returns this variant's associated string, whether borrowed or owned.
+            pub fn associated_str(&self) -> &'static str {
+                associated::Associated::get_associated(self).as_ref()
+            }
+        });
+    }
+    if is_static_cstr(associated_type) {
+        methods.extend(quote! {
+            /// Returns a pointer to this variant's associated C string, which is valid for `'static`.
+            pub fn as_ptr(&self) -> *const ::core::ffi::c_char {
+                associated::Associated::get_associated(self).as_ptr()
+            }
+        });
+    }
+    if is_static_path(associated_type) {
+        methods.extend(quote! {
+            /// Returns this variant's associated path as a string, if it's valid Unicode.
+            pub fn associated_str(&self) -> ::core::option::Option<&'static str> {
+                associated::Associated::get_associated(self).to_str()
+            }
+        });
+    }
+    if is_ordering(associated_type) {
+        methods.extend(quote! {
+            /// Returns whether this variant's associated ordering is `Less`.
+            pub fn is_less(&self) -> bool {
+                associated::Associated::get_associated(self).is_lt()
+            }
+
+            /// Returns whether this variant's associated ordering is `Equal`.
+            pub fn is_equal(&self) -> bool {
+                associated::Associated::get_associated(self).is_eq()
+            }
+
+            /// Returns whether this variant's associated ordering is `Greater`.
+            pub fn is_greater(&self) -> bool {
+                associated::Associated::get_associated(self).is_gt()
+            }
+        });
+    }
+    methods
+}
+
+/// Generates the match body for `get_associated`, evaluating to a `'static` reference to each value.
+///
+/// With `inline_const`, `#[assoc_const]` values are evaluated in inline `const` blocks rather than
+/// `const` items, since only the former can use the const generics of the enum. With `bind_fields`,
+/// the fields of each variant are bound for its expression to use.
+fn generate_match_body(
+    enum_ident: &Ident,
+    associated_type: &Type,
+    associated_variants: &[(&Ident, &Fields, Expr, AssocKind)],
+    inline_const: bool,
+    bind_fields: bool,
+) -> TokenStream2 {
+    let mut match_block = TokenStream2::new();
+    match_block.extend(
+        associated_variants
+            .iter()
+            .map(|(variant_ident, fields, expr, kind)| {
+                let pattern = if bind_fields {
+                    binding_pattern(fields)
+                } else {
+                    variant_pattern(fields)
+                };
+                match kind {
+                    AssocKind::Constant if inline_const => {
+                        quote! {
+                            #enum_ident::#variant_ident #pattern => &const {
+                                let associated: #associated_type = #expr;
+                                associated
+                            },
+                        }
+                    }
+                    AssocKind::Constant => {
+                        quote! {
+                            #enum_ident::#variant_ident #pattern => {
+                                const ASSOCIATED: #associated_type = #expr;
+                                &ASSOCIATED
+                            },
+                        }
+                    }
+                    AssocKind::Static => {
+                        // Ascribing the type at the user's expression makes a mismatch point there
+                        // rather than at the derive.
+                        let reference = quote_spanned! {associated_type.span()=>
+                            &'static #associated_type
+                        };
+                        // A diverging `#expr`, such as `unreachable!()`, leaves the binding unused.
+                        quote_spanned! {expr.span()=>
+                            #[allow(unreachable_code, unused_variables)]
+                            #enum_ident::#variant_ident #pattern => {
+                                let associated: #reference = #expr;
+                                associated
+                            },
+                        }
+                    }
+                    AssocKind::Lazy => {
+                        let init = generate_lazy_init(associated_type, expr);
+                        quote! {
+                            #enum_ident::#variant_ident #pattern => #init,
+                        }
+                    }
+                }
+            }),
+    );
+    match_block
+}
+
+/// Generates an expression initializing an `#[assoc_lazy]` value on first use, evaluating to a
+/// `'static` reference to it.
+///
+/// Only `&'static str` values go through `IntoLazy`, so that other expressions keep inferring their
+/// type from `Type`.
+fn generate_lazy_init(associated_type: &Type, expr: &Expr) -> TokenStream2 {
+    let value = if is_static_str(associated_type) {
+        quote_spanned! {expr.span()=>
+            associated::__private::IntoLazy::<#associated_type>::into_lazy(#expr)
+        }
+    } else {
+        quote_spanned! {expr.span()=>
+            {
+                let associated: #associated_type = #expr;
+                associated
+            }
+        }
+    };
+    quote! {
+        {
+            static ASSOCIATED: associated::__private::OnceLock<#associated_type> =
+                associated::__private::OnceLock::new();
+            ASSOCIATED.get_or_init(|| #value)
+        }
+    }
+}
+
+/// Generates a match body usable in a `const fn`, evaluating to each associated value by value.
+///
+/// `#[assoc]` values are copied out of their `'static` reference. Callers must ensure there are no
+/// `#[assoc_lazy]` values.
+fn generate_const_match_body(
+    enum_ident: &Ident,
+    associated_type: &Type,
+    associated_variants: &[(&Ident, &Fields, Expr, AssocKind)],
+) -> TokenStream2 {
+    associated_variants
+        .iter()
+        .map(|(variant_ident, fields, expr, kind)| {
+            let pattern = variant_pattern(fields);
+            match kind {
+                AssocKind::Constant => quote! {
+                    #enum_ident::#variant_ident #pattern => {
+                        const ASSOCIATED: #associated_type = #expr;
+                        ASSOCIATED
+                    },
+                },
+                AssocKind::Static => quote! {
+                    #enum_ident::#variant_ident #pattern => *#expr,
+                },
+                AssocKind::Lazy => unreachable!("`assoc_lazy` values aren't const"),
+            }
+        })
+        .collect()
+}
+
+/// Generates the match body for `AssociatedValue`, returning each associated value by value.
+///
+/// `#[assoc]` values are cloned out of their `'static` reference.
+fn generate_value_match_body(
+    enum_ident: &Ident,
+    associated_type: &Type,
+    associated_variants: &[(&Ident, &Fields, Expr, AssocKind)],
+) -> TokenStream2 {
+    associated_variants
+        .iter()
+        .map(|(variant_ident, fields, expr, kind)| {
+            let pattern = variant_pattern(fields);
+            match kind {
+                AssocKind::Constant => quote! {
+                    #enum_ident::#variant_ident #pattern => {
+                        const ASSOCIATED: #associated_type = #expr;
+                        ASSOCIATED
+                    },
+                },
+                AssocKind::Static => quote! {
+                    #enum_ident::#variant_ident #pattern => ::core::clone::Clone::clone(#expr),
+                },
+                AssocKind::Lazy => {
+                    let init = generate_lazy_init(associated_type, expr);
+                    quote! {
+                        #enum_ident::#variant_ident #pattern => ::core::clone::Clone::clone(#init),
+                    }
+                }
+            }
+        })
+        .collect()
+}
+
+/// Generates the pattern glyph that ignores the fields of a variant.
+fn variant_pattern(fields: &Fields) -> TokenStream2 {
+    match fields {
+        syn::Fields::Named(_) => quote! {{..}},
+        syn::Fields::Unnamed(_) => quote! {(..)},
+        syn::Fields::Unit => quote! {},
+    }
+}
+
+/// Generates the pattern glyph that binds the fields of a variant, by name or as `_0`, `_1`, etc.
+fn binding_pattern(fields: &Fields) -> TokenStream2 {
+    match fields {
+        syn::Fields::Named(named) => {
+            let names = named.named.iter().map(|field| &field.ident);
+            quote! {{ #(#names),* }}
+        }
+        syn::Fields::Unnamed(unnamed) => {
+            let names = (0..unnamed.unnamed.len())
+                .map(|i| Ident::new(&format!("_{}", i), Span::call_site()));
+            quote! {( #(#names),* )}
+        }
+        syn::Fields::Unit => quote! {},
+    }
+}
+
+/// Converts a `PascalCase` identifier to `snake_case`, keeping acronyms such as `HTTPServer` together.
+fn to_snake_case(ident: &str) -> String {
+    let chars: Vec<char> = ident.chars().collect();
+    let mut snake = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 && chars[i - 1] != '_' {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            if prev.is_lowercase() || prev.is_numeric() || (prev.is_uppercase() && next_is_lower) {
+                snake.push('_');
+            }
+        }
+        snake.extend(c.to_lowercase());
+    }
+    snake
+}
+
+/// The casings accepted by `rename_all`, named as in serde.
+const CASINGS: [&str; 8] = [
+    "lowercase",
+    "UPPERCASE",
+    "PascalCase",
+    "camelCase",
+    "snake_case",
+    "SCREAMING_SNAKE_CASE",
+    "kebab-case",
+    "SCREAMING-KEBAB-CASE",
+];
+
+/// Converts a `PascalCase` variant name to `casing`, one of [`CASINGS`].
+fn rename(name: &str, casing: &str) -> String {
+    match casing {
+        "lowercase" => name.to_lowercase(),
+        "UPPERCASE" => name.to_uppercase(),
+        "camelCase" => {
+            let mut chars = name.chars();
+            chars
+                .next()
+                .map(|first| first.to_lowercase().chain(chars).collect())
+                .unwrap_or_default()
+        }
+        "snake_case" => to_snake_case(name),
+        "SCREAMING_SNAKE_CASE" => to_snake_case(name).to_uppercase(),
+        "kebab-case" => to_snake_case(name).replace('_', "-"),
+        "SCREAMING-KEBAB-CASE" => to_snake_case(name).to_uppercase().replace('_', "-"),
+        _ => name.to_string(),
+    }
+}
+
+/// Generates a `<VARIANT>_ASSOCIATED` const for every `#[assoc_const]` variant.
+fn generate_exposed_consts(
+    enum_ident: &Ident,
+    associated_type: &Type,
+    associated_variants: &[(&Ident, &Fields, Expr, AssocKind)],
+) -> ParseResult<TokenStream2> {
+    let mut names: Vec<(String, &Ident)> = Vec::new();
+    let mut consts = TokenStream2::new();
+    for (variant_ident, _, expr, kind) in associated_variants {
+        if !matches!(kind, AssocKind::Constant) {
+            continue;
+        }
+        let name = format!(
+            "{}_ASSOCIATED",
+            to_snake_case(&variant_ident.unraw().to_string()).to_uppercase()
+        );
+        if let Some((_, other)) = names.iter().find(|(n, _)| *n == name) {
+            return Err(ParseError::new(
+                variant_ident.span(),
+                format!(
+                    "Cannot derive `Associated` for `{}`: `expose_consts` generates `{}` for both `{}` and `{}`",
+                    enum_ident, name, other, variant_ident
+                ),
+            ));
+        }
+        let const_ident = Ident::new(&name, variant_ident.span());
+        let doc = format!("The constant associated with `{}`.", variant_ident);
+        consts.extend(quote! {
+            #[doc = #doc]
+            pub const #const_ident: #associated_type = #expr;
+        });
+        names.push((name, variant_ident));
+    }
+    Ok(consts)
+}
+
+/// Generates `debug_associated`, pairing the name of every variant with its associated value.
+fn generate_debug_associated(
+    enum_ident: &Ident,
+    variants: &Punctuated<Variant, Comma>,
+    associated_type: &Type,
+) -> TokenStream2 {
+    let arms = variants.iter().map(|v| {
+        let variant_ident = &v.ident;
+        let pattern = variant_pattern(&v.fields);
+        let name = variant_ident.unraw().to_string();
+        quote! { #enum_ident::#variant_ident #pattern => #name, }
+    });
+    quote! {
+        /// Returns the name of this variant together with its associated value, for debugging.
+        pub fn debug_associated(&self) -> (&'static str, &'static #associated_type) {
+            let name = match self {
+                #(#arms)*
+            };
+            (name, associated::Associated::get_associated(self))
+        }
+    }
+}
+
+/// Generates `associated_at`, looking values up by index, erroring at `option` unless `variants`
+/// have the discriminants `0..N` and every value is const.
+fn generate_associated_at(
+    variants: &Punctuated<Variant, Comma>,
+    associated_type: &Type,
+    associated_variants: &[(&Ident, &Fields, Expr, AssocKind)],
+    option: &Ident,
+) -> ParseResult<TokenStream2> {
+    let dense = variants
+        .iter()
+        .enumerate()
+        .all(|(i, v)| match &v.discriminant {
+            None => true,
+            Some((
+                _,
+                Expr::Lit(ExprLit {
+                    lit: Lit::Int(int), ..
+                }),
+            )) => int.base10_parse::<usize>().is_ok_and(|d| d == i),
+            Some(_) => false,
+        });
+    let all_const = associated_variants
+        .iter()
+        .all(|(_, _, _, kind)| matches!(kind, AssocKind::Constant));
+    if !dense {
+        return Err(ParseError::new(
+            option.span(),
+            format!(
+                "`{}` requires the discriminants `0..N` in declaration order",
+                option
+            ),
+        ));
+    }
+    if !all_const {
+        return Err(ParseError::new(
+            option.span(),
+            format!("`{}` requires `assoc_const` for every variant", option),
+        ));
+    }
+    let values = associated_variants.iter().map(|(_, _, expr, _)| expr);
+    Ok(quote! {
+        /// Returns the constant associated with the variant at `index`, which is also its
+        /// discriminant.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `index` is out of bounds, which is a compile error in const contexts.
+        pub const fn associated_at(index: usize) -> &'static #associated_type {
+            const VALUES: &'static [#associated_type] = &[#(#values),*];
+            &VALUES[index]
+        }
+    })
+}
+
+/// Generates `next_variant` and `prev_variant`, stepping through `variants` in declaration order.
+///
+/// With `cycle` they wrap around and return `Self`, otherwise they return `None` past either end.
+fn generate_sequence(
+    enum_ident: &Ident,
+    variants: &Punctuated<Variant, Comma>,
+    cycle: bool,
+) -> TokenStream2 {
+    let idents: Vec<_> = variants.iter().map(|v| &v.ident).collect();
+    let step = |pairs: Vec<(&Ident, Option<&Ident>)>| -> TokenStream2 {
+        pairs
+            .into_iter()
+            .map(|(from, to)| match to {
+                Some(to) if cycle => quote! { #enum_ident::#from => #enum_ident::#to, },
+                Some(to) => {
+                    quote! { #enum_ident::#from => ::core::option::Option::Some(#enum_ident::#to), }
+                }
+                None => quote! { #enum_ident::#from => ::core::option::Option::None, },
+            })
+            .collect()
+    };
+    let len = idents.len();
+    let successor = |i: usize| match i + 1 {
+        next if next < len => Some(idents[next]),
+        _ if cycle => Some(idents[0]),
+        _ => None,
+    };
+    let predecessor = |i: usize| match i {
+        0 if cycle => Some(idents[len - 1]),
+        0 => None,
+        _ => Some(idents[i - 1]),
+    };
+    let next_arms = step((0..len).map(|i| (idents[i], successor(i))).collect());
+    let prev_arms = step((0..len).map(|i| (idents[i], predecessor(i))).collect());
+    let output = if cycle {
+        quote! { Self }
+    } else {
+        quote! { ::core::option::Option<Self> }
+    };
+    let (next_doc, prev_doc) = if cycle {
+        (
+            "Returns the variant declared after this one, wrapping around to the first.",
+            "Returns the variant declared before this one, wrapping around to the last.",
+        )
+    } else {
+        (
+            "Returns the variant declared after this one, or `None` for the last.",
+            "Returns the variant declared before this one, or `None` for the first.",
+        )
+    };
+    quote! {
+        #[doc = #next_doc]
+        pub const fn next_variant(&self) -> #output {
+            match self {
+                #next_arms
+            }
+        }
+
+        #[doc = #prev_doc]
+        pub const fn prev_variant(&self) -> #output {
+            match self {
+                #prev_arms
+            }
+        }
+    }
+}
+
+/// Generates an `is_<variant>` method for every variant.
+fn generate_predicates(
+    enum_ident: &Ident,
+    variants: &Punctuated<Variant, Comma>,
+) -> ParseResult<TokenStream2> {
+    let mut names: Vec<(String, &Ident)> = Vec::new();
+    let mut methods = TokenStream2::new();
+    for v in variants.iter() {
+        let variant_ident = &v.ident;
+        // Prefixed, the unraw name can't be a keyword.
+        let name = format!("is_{}", to_snake_case(&variant_ident.unraw().to_string()));
+        if let Some((_, other)) = names.iter().find(|(n, _)| *n == name) {
+            return Err(ParseError::new(
+                variant_ident.span(),
+                format!(
+                    "Cannot derive `Associated` for `{}`: `predicates` generates `{}` for both `{}` and `{}`",
+                    enum_ident, name, other, variant_ident
+                ),
+            ));
+        }
+        let method_ident = Ident::new(&name, variant_ident.span());
+        let pattern = variant_pattern(&v.fields);
+        let doc = format!(
+            "Returns whether this is `{}::{}`.",
+            enum_ident, variant_ident
+        );
+        methods.extend(quote! {
+            #[doc = #doc]
+            pub const fn #method_ident(&self) -> bool {
+                match self {
+                    #enum_ident::#variant_ident #pattern => true,
+                    #[allow(unreachable_patterns)]
+                    _ => false,
+                }
+            }
+        });
+        names.push((name, variant_ident));
+    }
+    Ok(methods)
+}
+
+/// Generates `with_field_associated`, binding the tuple field at `index` of every variant.
+fn generate_with_field_associated(
+    enum_ident: &Ident,
+    variants: &Punctuated<Variant, Comma>,
+    index: &LitInt,
+) -> ParseResult<TokenStream2> {
+    let position = index.base10_parse::<usize>()?;
+    let mut field_type = None;
+    let mut arms = TokenStream2::new();
+    for v in variants.iter() {
+        let field = match &v.fields {
+            Fields::Unnamed(fields) => fields.unnamed.iter().nth(position),
+            _ => None,
+        };
+        let field = match field {
+            Some(field) => field,
+            None => {
+                return Err(ParseError::new(
+                    v.span(),
+                    format!(
+                        "Cannot derive `Associated` for `{}`: `include_field = {}` requires variant `{}` to have tuple field {}",
+                        enum_ident, position, v.ident, position
+                    ),
+                ))
+            }
+        };
+        field_type.get_or_insert(&field.ty);
+        let variant_ident = &v.ident;
+        let skipped = (0..position).map(|_| quote! { _ });
+        arms.extend(quote! {
+            #enum_ident::#variant_ident(#(#skipped,)* field, ..) => ::core::clone::Clone::clone(field),
+        });
+    }
+    let doc = format!(
+        "Returns tuple field {} of this variant alongside its associated constant.",
+        position
+    );
+    Ok(quote! {
+        #[doc = #doc]
+        pub fn with_field_associated(&self) -> (#field_type, &'static <Self as associated::Associated>::AssociatedType) {
+            let field = match self {
+                #arms
+            };
+            (field, associated::Associated::get_associated(self))
+        }
+    })
+}
+
+/// Generates `PAIRS`, every discriminant with its associated value sorted by discriminant, and a
+/// binary search over it.
+fn generate_pairs(
+    enum_ident: &Ident,
+    associated_type: &Type,
+    associated_variants: &[(&Ident, &Fields, Expr, AssocKind)],
+    option: &Ident,
+) -> ParseResult<TokenStream2> {
+    let mut consts = TokenStream2::new();
+    let mut pairs = Vec::new();
+    for (i, (variant_ident, _, expr, kind)) in associated_variants.iter().enumerate() {
+        let value = match kind {
+            AssocKind::Constant => {
+                let value = Ident::new(&format!("VALUE_{}", i), Span::call_site());
+                consts.extend(quote! {
+                    const #value: #associated_type = #expr;
+                });
+                quote! { &#value }
+            }
+            AssocKind::Static => quote! { #expr },
+            AssocKind::Lazy => {
+                return Err(ParseError::new(
+                    expr.span(),
+                    format!(
+                    "`{}` requires const values, but `assoc_lazy` values are computed at runtime",
+                    option
+                ),
+                ))
+            }
+        };
+        pairs.push(quote! { (#enum_ident::#variant_ident as i64, #value) });
+    }
+    Ok(quote! {
+        /// The discriminant of every variant with its associated constant, sorted by discriminant.
+        pub const PAIRS: &'static [(i64, &'static #associated_type)] = &{
+            #consts
+            let mut pairs = [#(#pairs),*];
+            // Insertion sort, since slice sorting isn't const.
+            let mut i = 1;
+            while i < pairs.len() {
+                let mut j = i;
+                while j > 0 && pairs[j - 1].0 > pairs[j].0 {
+                    let swapped = pairs[j - 1];
+                    pairs[j - 1] = pairs[j];
+                    pairs[j] = swapped;
+                    j -= 1;
+                }
+                i += 1;
+            }
+            pairs
+        };
+
+        /// Returns the constant associated with the variant with `discriminant`, if any, by binary
+        /// search over `PAIRS`.
+        pub fn associated_by_discriminant(
+            discriminant: i64,
+        ) -> ::core::option::Option<&'static #associated_type> {
+            Self::PAIRS
+                .binary_search_by_key(&discriminant, |(d, _)| *d)
+                .ok()
+                .map(|index| Self::PAIRS[index].1)
+        }
+    })
+}
+
+/// Generates `resolve`, indexing the associated slice by each variant's first tuple field.
+fn generate_resolve(
+    enum_ident: &Ident,
+    variants: &Punctuated<Variant, Comma>,
+    associated_type: &Type,
+    option: &Ident,
+) -> ParseResult<TokenStream2> {
+    let element = static_slice_element(associated_type).ok_or_else(|| {
+        ParseError::new(option.span(), "`resolve` requires `Type = &'static [T]`")
+    })?;
+    let mut arms = TokenStream2::new();
+    for v in variants.iter() {
+        if !matches!(&v.fields, Fields::Unnamed(fields) if !fields.unnamed.is_empty()) {
+            return Err(ParseError::new(
+                v.span(),
+                format!(
+                    "Cannot derive `Associated` for `{}`: `resolve` requires variant `{}` to have an index as tuple field 0",
+                    enum_ident, v.ident
+                ),
+            ));
+        }
+        let variant_ident = &v.ident;
+        arms.extend(quote! {
+            #enum_ident::#variant_ident(index, ..) => *index,
+        });
+    }
+    Ok(quote! {
+        /// Returns the element of this variant's associated slice at the index in tuple field 0, or
+        /// `None` if it's out of bounds.
+        pub fn resolve(&self) -> ::core::option::Option<&'static #element> {
+            let index: usize = match self {
+                #arms
+            };
+            associated::Associated::get_associated(self).get(index)
+        }
+    })
+}
+
+/// Generates a check that fails compilation if two variants have equal associated values.
+///
+/// Integers, `char` and `&'static str` are compared pairwise in const assertions at the duplicate's
+/// expression. Other types can't be compared in const, so each value becomes a const pattern in one
+/// match, and a duplicate is rejected by the denied `unreachable_patterns` lint instead.
+fn generate_distinct_check(
+    associated_type: &Type,
+    associated_variants: &[(&Ident, &Fields, Expr, AssocKind)],
+    option: &Ident,
+) -> ParseResult<TokenStream2> {
+    let values = generate_const_values(associated_variants, option)?;
+    let consts: Vec<_> = (0..values.len())
+        .map(|i| Ident::new(&format!("VALUE_{}", i), Span::call_site()))
+        .collect();
+    let comparable = is_integer(associated_type) || is_char(associated_type);
+    if comparable || is_static_str(associated_type) {
+        let mut assertions = TokenStream2::new();
+        for (later, ((later_ident, _, expr, _), later_const)) in
+            associated_variants.iter().zip(&consts).enumerate()
+        {
+            for ((earlier_ident, ..), earlier_const) in
+                associated_variants.iter().zip(&consts).take(later)
+            {
+                let equal = if comparable {
+                    quote! { #later_const == #earlier_const }
+                } else {
+                    quote! { associated::__private::str_eq(#later_const, #earlier_const) }
+                };
+                let message = format!(
+                    "`{}` has the same associated value as `{}`",
+                    later_ident.unraw(),
+                    earlier_ident.unraw()
+                );
+                assertions.extend(quote_spanned! {expr.span()=>
+                    ::core::assert!(!(#equal), #message);
+                });
+            }
+        }
+        return Ok(quote! {
+            const _: () = {
+                #(const #consts: #associated_type = #values;)*
+                #assertions
+            };
+        });
+    }
+    let arms = associated_variants
+        .iter()
+        .zip(&consts)
+        .map(|((_, _, expr, _), value)| {
+            quote_spanned! {expr.span()=>
+                ::core::option::Option::Some(#value) => {}
+            }
+        });
+    Ok(quote! {
+        const _: () = {
+            #[allow(dead_code)]
+            #[deny(unreachable_patterns)]
+            fn distinct(value: ::core::option::Option<#associated_type>) {
+                #(const #consts: #associated_type = #values;)*
+                match value {
+                    #(#arms)*
+                    _ => {}
+                }
+            }
+        };
+    })
 }
 
-impl Parse for Args {
-    fn parse(input: ParseStream) -> ParseResult<Self> {
-        let b = Binding::parse(input)?;
-        if b.ident.to_string() == "Type" {
-            return Ok(Args { assoc_type: b.ty });
+/// Returns the integer type given in the enum's `#[repr]` attributes, or `isize` if there isn't one.
+///
+/// Other reprs, such as `C`, `transparent` or `align(N)`, are skipped wherever they appear.
+fn repr_type(attrs: &[Attribute]) -> ParseResult<Ident> {
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("repr")) {
+        let reprs = attr.parse_args_with(Punctuated::<Meta, Comma>::parse_terminated)?;
+        for repr in reprs {
+            if let Some(ident) = repr.path().get_ident() {
+                if INTEGERS.iter().any(|i| ident == i) {
+                    return Ok(ident.clone());
+                }
+            }
         }
-        Err(ParseError::new(b.ident.span(), "Expected `Type`"))
     }
+    Ok(Ident::new("isize", Span::call_site()))
 }
 
-fn generate_match_body(
+/// Generates the trait implementations requested through options in `#[associated]`.
+fn generate_trait_impls(
+    attrs: &[Attribute],
     enum_ident: &Ident,
-    associated_type: &Type,
-    associated_variants: &Vec<(&Ident, &Fields, Expr, AssocKind)>,
-) -> TokenStream2 {
-    let mut match_block = TokenStream2::new();
-    match_block.extend(
-        associated_variants
-            .iter()
-            .map(|(variant_ident, fields, expr, kind)| {
-                let pattern = match fields {
-                    syn::Fields::Named(_) => quote! {{..}},
-                    syn::Fields::Unnamed(_) => quote! {(..)},
-                    syn::Fields::Unit => quote! {},
-                };
-                match kind {
-                    AssocKind::Constant => {
-                        quote! {
-                            #enum_ident::#variant_ident #pattern => {
-                                const ASSOCIATED: #associated_type = #expr;
-                                &ASSOCIATED
-                            },
+    generics: &Generics,
+    variants: &Punctuated<Variant, Comma>,
+    args: &Args,
+    associated_variants: &[(&Ident, &Fields, Expr, AssocKind)],
+) -> ParseResult<TokenStream2> {
+    let associated_type = &args.assoc_type;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let mut impls = TokenStream2::new();
+    if let Some(option) = &args.into {
+        require_unit_variants(variants, enum_ident, option)?;
+        impls.extend(quote! {
+            impl #impl_generics ::core::convert::From<#enum_ident #ty_generics> for #associated_type #where_clause {
+                fn from(value: #enum_ident #ty_generics) -> Self {
+                    *associated::Associated::get_associated(&value)
+                }
+            }
+        });
+    }
+    if args.debug.is_some() {
+        impls.extend(quote! {
+            impl #impl_generics ::core::fmt::Debug for #enum_ident #ty_generics #where_clause {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    ::core::fmt::Debug::fmt(associated::Associated::get_associated(self), f)
+                }
+            }
+        });
+    }
+    if args.ord_by_assoc.is_some() {
+        impls.extend(quote! {
+            impl #impl_generics ::core::cmp::PartialOrd for #enum_ident #ty_generics #where_clause {
+                fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                    ::core::option::Option::Some(::core::cmp::Ord::cmp(self, other))
+                }
+            }
+            impl #impl_generics ::core::cmp::Ord for #enum_ident #ty_generics #where_clause {
+                fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                    ::core::cmp::Ord::cmp(
+                        associated::Associated::get_associated(self),
+                        associated::Associated::get_associated(other),
+                    )
+                }
+            }
+        });
+    }
+    if let Some(option) = &args.try_from {
+        require_unit_variants(variants, enum_ident, option)?;
+        let repr = repr_type(attrs)?;
+        let variant_idents = variants.iter().map(|v| &v.ident);
+        impls.extend(quote! {
+            impl #impl_generics ::core::convert::TryFrom<#repr> for #enum_ident #ty_generics #where_clause {
+                /// The value that isn't the discriminant of any variant.
+                type Error = #repr;
+                fn try_from(value: #repr) -> ::core::result::Result<Self, #repr> {
+                    #(
+                        if value == #enum_ident::#variant_idents as #repr {
+                            return ::core::result::Result::Ok(#enum_ident::#variant_idents);
                         }
-                    }
-                    AssocKind::Static => {
-                        quote! {
-                            #enum_ident::#variant_ident #pattern => #expr,
+                    )*
+                    ::core::result::Result::Err(value)
+                }
+            }
+        });
+    }
+    if let Some(option) = &args.from_str {
+        require_static_str(associated_type, option)?;
+        require_unit_variants(variants, enum_ident, option)?;
+        let values = generate_const_values(associated_variants, option)?;
+        let variant_idents = variants.iter().map(|v| &v.ident);
+        impls.extend(quote! {
+            impl #impl_generics ::core::str::FromStr for #enum_ident #ty_generics #where_clause {
+                type Err = associated::ParseAssociatedError;
+                fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                    const EXPECTED: &'static [&'static str] = &[#(#values),*];
+                    #(
+                        if *associated::Associated::get_associated(&#enum_ident::#variant_idents) == s {
+                            return ::core::result::Result::Ok(#enum_ident::#variant_idents);
+                        }
+                    )*
+                    ::core::result::Result::Err(associated::ParseAssociatedError::new(EXPECTED))
+                }
+            }
+        });
+    }
+    if let Some(option) = &args.value_enum {
+        require_static_str(associated_type, option)?;
+        require_unit_variants(variants, enum_ident, option)?;
+        let variant_idents = variants.iter().map(|v| &v.ident);
+        impls.extend(quote! {
+            impl #impl_generics associated::__private::clap::ValueEnum for #enum_ident #ty_generics #where_clause {
+                fn value_variants<'a>() -> &'a [Self] {
+                    &[#(#enum_ident::#variant_idents),*]
+                }
+                fn to_possible_value(
+                    &self,
+                ) -> ::core::option::Option<associated::__private::clap::builder::PossibleValue> {
+                    ::core::option::Option::Some(associated::__private::clap::builder::PossibleValue::new(
+                        *associated::Associated::get_associated(self),
+                    ))
+                }
+            }
+        });
+    }
+    if let Some(option) = &args.display_padded {
+        require_static_str(associated_type, option)?;
+        let values = generate_const_values(associated_variants, option)?;
+        impls.extend(quote! {
+            impl #impl_generics ::core::fmt::Display for #enum_ident #ty_generics #where_clause {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    /// The length in chars of the longest associated string.
+                    const MAX_LEN: usize = {
+                        let values: &[&str] = &[#(#values),*];
+                        let mut max = 0;
+                        let mut i = 0;
+                        while i < values.len() {
+                            let bytes = values[i].as_bytes();
+                            // Counts every byte except UTF-8 continuation bytes.
+                            let mut len = 0;
+                            let mut j = 0;
+                            while j < bytes.len() {
+                                if bytes[j] & 0xC0 != 0x80 {
+                                    len += 1;
+                                }
+                                j += 1;
+                            }
+                            if len > max {
+                                max = len;
+                            }
+                            i += 1;
                         }
+                        max
+                    };
+                    ::core::write!(f, "{:<1$}", associated::Associated::get_associated(self), MAX_LEN)
+                }
+            }
+        });
+    }
+    if args.eq_value.is_some() {
+        impls.extend(if is_static_str(associated_type) {
+            quote! {
+                impl #impl_generics ::core::cmp::PartialEq<str> for #enum_ident #ty_generics #where_clause {
+                    fn eq(&self, other: &str) -> bool {
+                        *associated::Associated::get_associated(self) == other
                     }
                 }
-            }),
-    );
-    match_block
+                impl #impl_generics ::core::cmp::PartialEq<&str> for #enum_ident #ty_generics #where_clause {
+                    fn eq(&self, other: &&str) -> bool {
+                        associated::Associated::get_associated(self) == other
+                    }
+                }
+            }
+        } else {
+            quote! {
+                impl #impl_generics ::core::cmp::PartialEq<#associated_type> for #enum_ident #ty_generics #where_clause {
+                    fn eq(&self, other: &#associated_type) -> bool {
+                        associated::Associated::get_associated(self) == other
+                    }
+                }
+            }
+        });
+    }
+    Ok(impls)
+}
+
+/// The attribute names an association is read from.
+///
+/// Each association is read only from its own names, so several can be given on one variant, and
+/// attributes of other derives, whatever they're called, are never mistaken for one.
+struct AssocNames {
+    /// Takes an expression of type `&'static Type`.
+    static_attr: &'static str,
+    /// Takes a const expression of type `Type`.
+    const_attr: &'static str,
+    /// Takes an expression computed on first use, if supported.
+    lazy_attr: Option<&'static str>,
+    /// Takes a string literal to associate uppercased, if supported.
+    upper_attr: Option<&'static str>,
+}
+
+/// Attributes for the value returned by `get_associated`.
+const ASSOC: AssocNames = AssocNames {
+    static_attr: "assoc",
+    const_attr: "assoc_const",
+    lazy_attr: Some("assoc_lazy"),
+    upper_attr: Some("assoc_upper"),
+};
+
+/// Attributes for the value returned by `get_secondary`.
+const ASSOC_SECONDARY: AssocNames = AssocNames {
+    static_attr: "assoc_secondary",
+    const_attr: "assoc_secondary_const",
+    lazy_attr: None,
+    upper_attr: None,
+};
+
+/// Returns the name of `attr` if its path is a single identifier, ignoring any leading `::`.
+fn attr_name(attr: &Attribute) -> Option<&Ident> {
+    match attr.path.segments.len() {
+        1 => Some(&attr.path.segments[0].ident),
+        _ => None,
+    }
+}
+
+/// Returns the value given in `assoc`, uppercasing it for `#[assoc_upper]`.
+fn assoc_value(assoc: &Assoc) -> ParseResult<Expr> {
+    if assoc.uppercase {
+        let name = assoc.attr.parse_args::<LitStr>()?;
+        let upper = LitStr::new(&name.value().to_uppercase(), name.span());
+        Ok(parse_quote!(#upper))
+    } else {
+        parse_assoc_expr(assoc.attr)
+    }
+}
+
+/// Finds the first attribute among `attrs` named in `names`.
+fn find_assoc<'a>(attrs: &'a [Attribute], names: &AssocNames) -> Option<Assoc<'a>> {
+    attrs.iter().find_map(|attr| match attr_name(attr) {
+        Some(i) => {
+            if i == names.static_attr {
+                Some(Assoc {
+                    kind: AssocKind::Static,
+                    attr,
+                    uppercase: false,
+                })
+            } else if i == names.const_attr {
+                Some(Assoc {
+                    kind: AssocKind::Constant,
+                    attr,
+                    uppercase: false,
+                })
+            } else if names.lazy_attr.is_some_and(|lazy_attr| i == lazy_attr) {
+                Some(Assoc {
+                    kind: AssocKind::Lazy,
+                    attr,
+                    uppercase: false,
+                })
+            } else if names.upper_attr.is_some_and(|upper_attr| i == upper_attr) {
+                Some(Assoc {
+                    kind: AssocKind::Constant,
+                    attr,
+                    uppercase: true,
+                })
+            } else {
+                None
+            }
+        }
+        None => None,
+    })
 }
 
 /// Takes in a sequence of enum variants and parses their attributes to return a list of (variant, associated value) groupings.
 ///
 /// Fields are included in the grouping to control which pattern glyph to generate for that variant.
-/// AssocKind holds whether the attribute was assoc or assoc_const
+/// AssocKind holds whether the attribute was the static, const or lazy attribute in `names`.
+///
+/// Variants without an attribute fall back to the defaults enabled in `defaults`, if given.
 fn parse_associated_values<'a>(
     variants: &'a Punctuated<Variant, Comma>,
     enum_ident: &Ident,
-) -> Result<Vec<(&'a Ident, &'a Fields, Expr, AssocKind)>, TokenStream> {
+    names: &AssocNames,
+    defaults: Option<&Args>,
+    trait_name: &str,
+) -> ParseResult<Vec<(&'a Ident, &'a Fields, Expr, AssocKind)>> {
+    let rename_all = defaults.and_then(|args| args.rename_all.as_ref());
+    let prefix = defaults.and_then(|args| args.prefix.as_ref());
+    let suffix = defaults.and_then(|args| args.suffix.as_ref());
+    let default_name = defaults
+        .and_then(|args| args.default_name.as_ref())
+        .or(rename_all.map(|(option, _)| option))
+        .or(prefix.map(|(option, _)| option))
+        .or(suffix.map(|(option, _)| option));
+    let from_doc = defaults.and_then(|args| args.from_doc.as_ref());
+    if let Some(args) = defaults {
+        for option in default_name.iter().chain(&from_doc) {
+            require_static_str(&args.assoc_type, option)?;
+        }
+    }
     let mut associated_values = Vec::new();
-    for v in variants.iter() {
-        if let Some(assoc) = v.attrs.iter().find_map(|attr| match attr.path.get_ident() {
-            Some(i) => {
-                let i = i.to_string();
-                if i == "assoc" {
-                    Some(Assoc {
-                        kind: AssocKind::Static,
-                        attr,
-                    })
-                } else if i == "assoc_const" {
-                    Some(Assoc {
-                        kind: AssocKind::Constant,
-                        attr,
-                    })
-                } else {
-                    None
-                }
+    if defaults.is_some_and(|args| args.id.is_some()) {
+        for v in variants.iter() {
+            let (name, _) = parse_pair(v, enum_ident, trait_name)?;
+            associated_values.push((&v.ident, &v.fields, name, AssocKind::Constant));
+        }
+        return Ok(associated_values);
+    }
+    if let Some((option, via)) = defaults.and_then(|args| args.via.as_ref()) {
+        require_unit_variants(variants, enum_ident, option)?;
+        for v in variants.iter() {
+            // A variant's own attribute takes precedence over `via`.
+            if let Some(assoc) = find_assoc(&v.attrs, names) {
+                associated_values.push((&v.ident, &v.fields, assoc_value(&assoc)?, assoc.kind));
+                continue;
             }
+            let variant_ident = &v.ident;
+            associated_values.push((
+                variant_ident,
+                &v.fields,
+                parse_quote!(#via(#enum_ident::#variant_ident)),
+                AssocKind::Constant,
+            ));
+        }
+        return Ok(associated_values);
+    }
+    for v in variants.iter() {
+        let doc = match from_doc {
+            Some(_) => doc_string(&v.attrs),
             None => None,
-        }) {
-            let expr = match assoc.attr.parse_args::<Expr>() {
-                Ok(expr) => expr,
-                Err(e) => return Err(e.to_compile_error().into()),
+        };
+        if let Some(assoc) = find_assoc(&v.attrs, names) {
+            associated_values.push((&v.ident, &v.fields, assoc_value(&assoc)?, assoc.kind));
+        } else if let Some(doc) = doc {
+            let doc = LitStr::new(&doc, v.ident.span());
+            associated_values.push((&v.ident, &v.fields, parse_quote!(#doc), AssocKind::Constant));
+        } else if let (Some(_), Fields::Unit) = (default_name, &v.fields) {
+            let name = v.ident.unraw().to_string();
+            let name = match rename_all {
+                Some((_, casing)) => rename(&name, &casing.value()),
+                None => name,
             };
-
-            associated_values.push((&v.ident, &v.fields, expr, assoc.kind));
+            let name = format!(
+                "{}{}{}",
+                prefix.map(|(_, p)| p.value()).unwrap_or_default(),
+                name,
+                suffix.map(|(_, s)| s.value()).unwrap_or_default()
+            );
+            let name = LitStr::new(&name, v.ident.span());
+            associated_values.push((
+                &v.ident,
+                &v.fields,
+                parse_quote!(#name),
+                AssocKind::Constant,
+            ));
         } else {
             return Err(ParseError::new(
                 v.span(),
                 format!(
-                    "Cannot derive `Associated` for `{}`: Missing `assoc` or `assoc_const` attribute on variant `{}`",
-                    enum_ident.to_string(),
-                    v.ident.to_string()
-                )
+                    "Cannot derive `{}` for `{}`: Missing `{}` or `{}` attribute{} on variant `{}`",
+                    trait_name,
+                    enum_ident,
+                    names.static_attr,
+                    names.const_attr,
+                    if from_doc.is_some() {
+                        " or doc comment"
+                    } else {
+                        ""
+                    },
+                    v.ident
+                ),
+            ));
+        }
+    }
+    Ok(associated_values)
+}
+
+/// Parses the `#[assoc_pair(value, id)]` attribute of `variant`, which is required with `id`.
+fn parse_pair(
+    variant: &Variant,
+    enum_ident: &Ident,
+    trait_name: &str,
+) -> ParseResult<(Expr, Expr)> {
+    let attr = variant
+        .attrs
+        .iter()
+        .find(|attr| attr_name(attr).is_some_and(|i| i == "assoc_pair"))
+        .ok_or_else(|| {
+            ParseError::new(
+                variant.span(),
+                format!(
+                    "Cannot derive `{}` for `{}`: Missing `assoc_pair` attribute on variant `{}`",
+                    trait_name, enum_ident, variant.ident
+                ),
+            )
+        })?;
+    attr.parse_args_with(|input: ParseStream| {
+        let value = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let id = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        Ok((value, id))
+    })
+}
+
+/// Parses the expression given to an association attribute.
+///
+/// syn 1 panics on C string literals, so expressions containing one are passed on unparsed and
+/// left for the compiler to check.
+fn parse_assoc_expr(attr: &Attribute) -> ParseResult<Expr> {
+    let tokens = attr.parse_args::<TokenStream2>()?;
+    if contains_c_str(tokens.clone()) {
+        Ok(Expr::Verbatim(tokens))
+    } else {
+        syn::parse2(tokens)
+    }
+}
+
+/// Returns whether `tokens` contain a C string literal, such as `c"Alpha"`.
+fn contains_c_str(tokens: TokenStream2) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Literal(literal) => {
+            let literal = literal.to_string();
+            literal.starts_with("c\"") || literal.starts_with("cr\"") || literal.starts_with("cr#")
+        }
+        TokenTree::Group(group) => contains_c_str(group.stream()),
+        _ => false,
+    })
+}
+
+/// Returns the text of the doc comments in `attrs`, with each line and the whole text trimmed.
+///
+/// Doc attributes that aren't string literals, such as `#[doc = include_str!(..)]`, are skipped.
+fn doc_string(attrs: &[Attribute]) -> Option<String> {
+    let mut lines = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("doc")) {
+        if let Ok(Meta::NameValue(MetaNameValue {
+            lit: Lit::Str(line),
+            ..
+        })) = attr.parse_meta()
+        {
+            lines.push(line.value().trim().to_owned());
+        }
+    }
+    let doc = lines.join("\n").trim().to_owned();
+    if doc.is_empty() {
+        None
+    } else {
+        Some(doc)
+    }
+}
+
+/// Parses the `#[associated]` attribute and variants shared by every derive in this crate.
+fn parse_enum_input(
+    attrs: &[Attribute],
+    ident: &Ident,
+    generics: &Generics,
+    data: syn::Data,
+    trait_name: &str,
+    attr_ident: &str,
+) -> Result<(Args, Punctuated<Variant, Comma>), TokenStream> {
+    // Checked before the attribute so that any struct or union gets the same error. The match is
+    // exhaustive, so a new kind of `syn::Data` must be handled here when upgrading syn.
+    let variants = match data {
+        syn::Data::Struct(s) => {
+            return Err(ParseError::new(
+                s.struct_token.span,
+                format!("Cannot derive `{}` for structs", trait_name),
+            )
+            .to_compile_error()
+            .into())
+        }
+        syn::Data::Union(u) => {
+            return Err(ParseError::new(
+                u.union_token.span,
+                format!("Cannot derive `{}` for unions", trait_name),
             )
             .to_compile_error()
-            .into());
+            .into())
+        }
+        syn::Data::Enum(data) => data.variants,
+    };
+    let associated = match attrs.iter().find(|attr| attr_name(attr).is_some_and(|i| i == attr_ident)) {
+        Some(attr) => attr,
+        None => {
+            return Err(
+                ParseError::new(
+                    ident.span(),
+                    format!("Missing `{}` attribute. If it's behind `cfg_attr`, put the derive behind the same condition", attr_ident),
+                )
+                    .to_compile_error()
+                    .into(),
+            )
         }
+    };
+    // A bare `#[associated]` takes every default.
+    let args = if associated.tokens.is_empty() {
+        syn::parse2::<Args>(TokenStream2::new())
+    } else {
+        associated.parse_args::<Args>()
+    };
+    let mut args = match args {
+        Ok(a) => a,
+        Err(e) => return Err(e.to_compile_error().into()),
+    };
+    // Every derive parses the same options, so those it doesn't implement are rejected here.
+    let applies = |option: &Ident| match (trait_name, attr_ident) {
+        (_, "associated_with") => option == "Type" || option == "Context",
+        ("AssociatedValue", _) => VALUE_OPTIONS.iter().any(|o| option == o),
+        _ => option != "Context",
+    };
+    if let Some(option) = args.given.iter().find(|option| !applies(option)) {
+        let hint = if trait_name == "AssociatedValue" && attr_ident == "associated" {
+            ". To derive both `Associated` and `AssociatedValue`, give `AssociatedValue` its own `#[associated_value]` attribute"
+        } else {
+            ""
+        };
+        return Err(ParseError::new(
+            option.span(),
+            format!(
+                "Option `{}` doesn't apply to `#[{}]` when deriving `{}`{}",
+                option, attr_ident, trait_name, hint
+            ),
+        )
+        .to_compile_error()
+        .into());
     }
-    Ok(associated_values)
+    let (_, ty_generics, _) = generics.split_for_impl();
+    let self_ty = quote! { #ident #ty_generics };
+    args.assoc_type = replace_self(&args.assoc_type, &self_ty);
+    args.secondary_type = args.secondary_type.map(|ty| replace_self(&ty, &self_ty));
+    Ok((args, variants))
+}
+
+/// The options `AssociatedValue` implements, all of which only decide the associated values.
+const VALUE_OPTIONS: [&str; 7] = [
+    "Type",
+    "default_name",
+    "rename_all",
+    "prefix",
+    "suffix",
+    "from_doc",
+    "via",
+];
+
+/// Returns `ty` with every `Self` replaced by `self_ty`, since the consts generated for values are
+/// nested items, which can't name `Self`.
+fn replace_self(ty: &Type, self_ty: &TokenStream2) -> Type {
+    fn replace(tokens: TokenStream2, self_ty: &TokenStream2) -> TokenStream2 {
+        tokens
+            .into_iter()
+            .flat_map(|token| match token {
+                TokenTree::Ident(ident) if ident == "Self" => self_ty.clone(),
+                TokenTree::Group(group) => {
+                    let mut replaced =
+                        Group::new(group.delimiter(), replace(group.stream(), self_ty));
+                    replaced.set_span(group.span());
+                    TokenTree::Group(replaced).into()
+                }
+                token => token.into(),
+            })
+            .collect()
+    }
+    // Swapping a type for a path to another keeps the tokens a valid type.
+    syn::parse2(replace(ty.to_token_stream(), self_ty)).unwrap_or_else(|_| ty.clone())
 }
 
 /// See [crate-level] documentation.
 ///
 /// [crate-level]: crate
-#[proc_macro_derive(Associated, attributes(associated, assoc, assoc_const))]
+#[proc_macro_derive(
+    Associated,
+    attributes(
+        associated,
+        assoc,
+        assoc_const,
+        assoc_lazy,
+        assoc_upper,
+        assoc_pair,
+        assoc_secondary,
+        assoc_secondary_const
+    )
+)]
 pub fn associated_derive(input: TokenStream) -> TokenStream {
     let DeriveInput {
         attrs,
@@ -187,55 +3948,368 @@ pub fn associated_derive(input: TokenStream) -> TokenStream {
         generics,
         data,
     } = parse_macro_input!(input);
-    let associated = match (&attrs).iter().find(|&attr| match attr.path.get_ident() {
-        Some(i) => i.to_string() == "associated",
-        None => false,
-    }) {
-        Some(attr) => attr,
-        None => {
-            return ParseError::new(ident.span(), "Missing `associated` attribute")
-                .to_compile_error()
-                .into()
-        }
-    };
-    let args = match associated.parse_args::<Args>() {
-        Ok(a) => a,
+    let (args, variants) =
+        match parse_enum_input(&attrs, &ident, &generics, data, "Associated", "associated") {
+            Ok(parsed) => parsed,
+            Err(e) => return e,
+        };
+    let associated_variants =
+        match parse_associated_values(&variants, &ident, &ASSOC, Some(&args), "Associated") {
+            Ok(v) => v,
+            Err(e) => return e.to_compile_error().into(),
+        };
+    let inherent_items =
+        match generate_inherent_items(&ident, &generics, &variants, &args, &associated_variants) {
+            Ok(items) => items,
+            Err(e) => return e.to_compile_error().into(),
+        };
+    let trait_impls = match generate_trait_impls(
+        &attrs,
+        &ident,
+        &generics,
+        &variants,
+        &args,
+        &associated_variants,
+    ) {
+        Ok(impls) => impls,
         Err(e) => return e.to_compile_error().into(),
     };
+    let associated_type = &args.assoc_type;
 
-    let variants = match data {
-        syn::Data::Struct(s) => {
+    let inline_const = generics.const_params().next().is_some();
+    let bind_fields = args.bind_fields.is_some();
+    let match_block = generate_match_body(
+        &ident,
+        associated_type,
+        &associated_variants,
+        inline_const,
+        bind_fields,
+    );
+    // Not every expression uses every field it's given.
+    let allow_unused = if bind_fields {
+        quote! { #[allow(unused_variables)] }
+    } else {
+        TokenStream2::new()
+    };
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let mut impl_block = quote! {
+        impl #impl_generics associated::Associated for #ident #ty_generics #where_clause {
+            type AssociatedType = #associated_type;
+            #allow_unused
+            fn get_associated(&self) -> &'static Self::AssociatedType {
+                match self {
+                    #match_block
+                }
+            }
+        }
+    };
+    if !inherent_items.is_empty() {
+        impl_block.extend(quote! {
+            impl #impl_generics #ident #ty_generics #where_clause {
+                #inherent_items
+            }
+        });
+    }
+    impl_block.extend(trait_impls);
+    if let Some(option) = &args.distinct {
+        match generate_distinct_check(associated_type, &associated_variants, option) {
+            Ok(check) => impl_block.extend(check),
+            Err(e) => return e.to_compile_error().into(),
+        }
+    }
+    wrap_in_const(impl_block).into()
+}
+
+/// Derives [`AssociatedWith`] from an `#[associated_with(Type = T, Context = C)]` attribute and an
+/// `#[assoc_with(expr)]` attribute on every variant, where `expr` may borrow from `ctx: &C`. See
+/// [crate-level] documentation.
+///
+/// [`AssociatedWith`]: https://docs.rs/associated/latest/associated/trait.AssociatedWith.html
+/// [crate-level]: crate
+#[proc_macro_derive(AssociatedWith, attributes(associated_with, assoc_with))]
+pub fn associated_with_derive(input: TokenStream) -> TokenStream {
+    let DeriveInput {
+        attrs,
+        vis: _,
+        ident,
+        generics,
+        data,
+    } = parse_macro_input!(input);
+    let (args, variants) = match parse_enum_input(
+        &attrs,
+        &ident,
+        &generics,
+        data,
+        "AssociatedWith",
+        "associated_with",
+    ) {
+        Ok(parsed) => parsed,
+        Err(e) => return e,
+    };
+    let context = match &args.context {
+        Some(context) => context,
+        None => {
             return ParseError::new(
-                s.struct_token.span,
-                "Cannot derive `Associated` for structs",
+                ident.span(),
+                format!(
+                    "Cannot derive `AssociatedWith` for `{}`: Missing `Context = ...` in `associated_with` attribute",
+                    ident
+                ),
             )
             .to_compile_error()
             .into()
         }
-        syn::Data::Union(u) => {
-            return ParseError::new(u.union_token.span, "Cannot derive `Associated` for unions")
+    };
+    let associated_type = &args.assoc_type;
+    let mut match_block = TokenStream2::new();
+    for v in variants.iter() {
+        let attr = match v
+            .attrs
+            .iter()
+            .find(|attr| attr_name(attr).is_some_and(|i| i == "assoc_with"))
+        {
+            Some(attr) => attr,
+            None => {
+                return ParseError::new(
+                    v.span(),
+                    format!(
+                        "Cannot derive `AssociatedWith` for `{}`: Missing `assoc_with` attribute on variant `{}`",
+                        ident, v.ident
+                    ),
+                )
                 .to_compile_error()
                 .into()
+            }
+        };
+        let expr = match parse_assoc_expr(attr) {
+            Ok(expr) => expr,
+            Err(e) => return e.to_compile_error().into(),
+        };
+        let variant_ident = &v.ident;
+        let pattern = variant_pattern(&v.fields);
+        let reference = quote_spanned! {associated_type.span()=>
+            &'associated #associated_type
+        };
+        match_block.extend(quote_spanned! {expr.span()=>
+            #ident::#variant_ident #pattern => {
+                let associated: #reference = #expr;
+                associated
+            },
+        });
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let impl_block = quote! {
+        impl #impl_generics associated::AssociatedWith<#context> for #ident #ty_generics #where_clause {
+            type AssociatedType = #associated_type;
+            #[allow(unused_variables)]
+            fn get_associated_with<'associated>(
+                &self,
+                ctx: &'associated #context,
+            ) -> &'associated Self::AssociatedType {
+                match self {
+                    #match_block
+                }
+            }
         }
-        syn::Data::Enum(data) => data.variants,
     };
-    let associated_variants = match parse_associated_values(&variants, &ident) {
-        Ok(v) => v,
+    wrap_in_const(impl_block).into()
+}
+
+/// The input of `impl_associated!`: `Enum, [Type = T,] { Variant => const_expr, ... }`.
+struct ImplAssociatedInput {
+    enum_path: syn::Path,
+    assoc_type: Type,
+    arms: Punctuated<(Ident, Expr), Comma>,
+}
+
+impl Parse for ImplAssociatedInput {
+    fn parse(input: ParseStream) -> ParseResult<Self> {
+        let enum_path = input.parse()?;
+        input.parse::<Comma>()?;
+        let assoc_type = if input.peek(Ident) {
+            let option: Ident = input.parse()?;
+            if option != "Type" {
+                return Err(ParseError::new(
+                    option.span(),
+                    "Expected `Type = ...` or `{`",
+                ));
+            }
+            input.parse::<Token![=]>()?;
+            let assoc_type = input.parse()?;
+            input.parse::<Comma>()?;
+            assoc_type
+        } else {
+            parse_quote!(&'static str)
+        };
+        let content;
+        syn::braced!(content in input);
+        let arms = content.parse_terminated(|arm: ParseStream| {
+            let variant = arm.parse()?;
+            arm.parse::<Token![=>]>()?;
+            Ok((variant, arm.parse()?))
+        })?;
+        Ok(Self {
+            enum_path,
+            assoc_type,
+            arms,
+        })
+    }
+}
+
+/// Implements [`Associated`] for an enum without attributes on it, from a list of variants and their
+/// const values. See [crate-level] documentation.
+///
+/// [`Associated`]: https://docs.rs/associated/latest/associated/trait.Associated.html
+/// [crate-level]: crate
+#[proc_macro]
+pub fn impl_associated(input: TokenStream) -> TokenStream {
+    let ImplAssociatedInput {
+        enum_path,
+        assoc_type,
+        arms,
+    } = parse_macro_input!(input);
+    // `{ .. }` matches unit, tuple and struct variants alike, since their fields aren't known here.
+    let fields = Fields::Named(parse_quote!({}));
+    let associated_variants: Vec<_> = arms
+        .iter()
+        .map(|(variant, expr)| (variant, &fields, expr.clone(), AssocKind::Constant))
+        .collect();
+    let self_ident = Ident::new("Self", Span::call_site());
+    let match_block =
+        generate_match_body(&self_ident, &assoc_type, &associated_variants, false, false);
+    wrap_in_const(quote! {
+        impl associated::Associated for #enum_path {
+            type AssociatedType = #assoc_type;
+            fn get_associated(&self) -> &'static Self::AssociatedType {
+                match self {
+                    #match_block
+                }
+            }
+        }
+    })
+    .into()
+}
+
+/// The input of `assert_all_associated!`: `Enum, [Variant, ...]`.
+struct AssertAllAssociatedInput {
+    enum_path: syn::Path,
+    variants: Punctuated<Ident, Comma>,
+}
+
+impl Parse for AssertAllAssociatedInput {
+    fn parse(input: ParseStream) -> ParseResult<Self> {
+        let enum_path = input.parse()?;
+        input.parse::<Comma>()?;
+        let content;
+        syn::bracketed!(content in input);
+        let variants = content.parse_terminated(Ident::parse)?;
+        input.parse::<Option<Comma>>()?;
+        Ok(Self {
+            enum_path,
+            variants,
+        })
+    }
+}
+
+/// Asserts at compile time that an enum implements [`Associated`] and that the listed variants are
+/// all of its variants. See [crate-level] documentation.
+///
+/// [`Associated`]: https://docs.rs/associated/latest/associated/trait.Associated.html
+/// [crate-level]: crate
+#[proc_macro]
+pub fn assert_all_associated(input: TokenStream) -> TokenStream {
+    let AssertAllAssociatedInput {
+        enum_path,
+        variants,
+    } = parse_macro_input!(input);
+    let variants = variants.iter();
+    wrap_in_const(quote! {
+        fn assert_associated<T: ?::core::marker::Sized + associated::Associated>() {}
+
+        #[allow(dead_code)]
+        fn assert_all_associated(value: &#enum_path) {
+            assert_associated::<#enum_path>();
+            match value {
+                #(#enum_path::#variants { .. } => {})*
+            }
+        }
+    })
+    .into()
+}
+
+/// Wraps generated items in an anonymous const so nothing but their impls leaks into the user's module.
+fn wrap_in_const(items: TokenStream2) -> TokenStream2 {
+    quote! {
+        const _: () = {
+            #items
+        };
+    }
+}
+
+/// Derives [`AssociatedValue`] from the same attributes as [`Associated`], returning each value by
+/// value instead of by `'static` reference. Its options can be given in `#[associated_value]` instead
+/// of `#[associated]`, for deriving both. See [crate-level] documentation.
+///
+/// [`AssociatedValue`]: https://docs.rs/associated/latest/associated/trait.AssociatedValue.html
+/// [`Associated`]: macro@Associated
+/// [crate-level]: crate
+#[proc_macro_derive(
+    AssociatedValue,
+    attributes(
+        associated,
+        associated_value,
+        assoc,
+        assoc_const,
+        assoc_lazy,
+        assoc_upper
+    )
+)]
+pub fn associated_value_derive(input: TokenStream) -> TokenStream {
+    let DeriveInput {
+        attrs,
+        vis: _,
+        ident,
+        generics,
+        data,
+    } = parse_macro_input!(input);
+    // Deriving `Associated` too needs a separate attribute, since each rejects the other's options.
+    let attr_ident = if attrs
+        .iter()
+        .any(|attr| attr_name(attr).is_some_and(|i| i == "associated_value"))
+    {
+        "associated_value"
+    } else {
+        "associated"
+    };
+    let (args, variants) = match parse_enum_input(
+        &attrs,
+        &ident,
+        &generics,
+        data,
+        "AssociatedValue",
+        attr_ident,
+    ) {
+        Ok(parsed) => parsed,
         Err(e) => return e,
     };
+    let associated_variants =
+        match parse_associated_values(&variants, &ident, &ASSOC, Some(&args), "AssociatedValue") {
+            Ok(v) => v,
+            Err(e) => return e.to_compile_error().into(),
+        };
     let associated_type = args.assoc_type;
 
-    let match_block = generate_match_body(&ident, &associated_type, &associated_variants);
+    let match_block = generate_value_match_body(&ident, &associated_type, &associated_variants);
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     let impl_block = quote! {
-        impl #impl_generics associated::Associated for #ident #ty_generics #where_clause {
+        impl #impl_generics associated::AssociatedValue for #ident #ty_generics #where_clause {
             type AssociatedType = #associated_type;
-            fn get_associated(&self) -> &'static Self::AssociatedType {
+            fn associated_value(&self) -> Self::AssociatedType {
                 match self {
                     #match_block
                 }
             }
         }
     };
-    impl_block.into()
+    wrap_in_const(impl_block).into()
 }
@@ -0,0 +1,438 @@
+use associated::Associated;
+
+mod consts {
+    pub const ALPHA: u32 = 1;
+    pub static BRAVO: u32 = 2;
+
+    pub mod phonetic {
+        use associated::Associated;
+
+        #[derive(Associated)]
+        #[associated(Type = u32)]
+        pub enum Phonetic {
+            #[assoc_const(crate::consts::ALPHA)]
+            Alpha,
+            #[assoc(&super::BRAVO)]
+            Bravo,
+            #[assoc_const(core::primitive::u32::MAX)]
+            Charlie,
+        }
+    }
+}
+
+#[test]
+fn default_type() {
+    #[derive(Associated)]
+    #[associated]
+    enum Phonetic {
+        #[assoc_const("Alpha")]
+        Alpha,
+        #[assoc(&"Bravo")]
+        Bravo = 3,
+    }
+
+    assert_eq!(Phonetic::Alpha.get_associated(), &"Alpha");
+    assert_eq!(Phonetic::Bravo.get_associated(), &"Bravo");
+}
+
+#[test]
+fn generated_items_stay_private() {
+    // Names used internally by the derive remain free in this module.
+    const ASSOCIATED: u8 = 0;
+    const VALUE_0: u8 = 0;
+
+    #[derive(Associated)]
+    #[associated(Type = u8, distinct, expose_consts)]
+    enum Low {
+        #[assoc_const(1)]
+        One,
+    }
+
+    #[derive(Associated)]
+    #[associated(Type = u8, distinct, expose_consts)]
+    enum High {
+        #[assoc_const(255)]
+        One,
+    }
+
+    assert_eq!((ASSOCIATED, VALUE_0), (0, 0));
+    assert_eq!(Low::One.get_associated(), &Low::ONE_ASSOCIATED);
+    assert_eq!(High::One.get_associated(), &High::ONE_ASSOCIATED);
+}
+
+#[test]
+fn paths() {
+    use consts::phonetic::Phonetic;
+
+    assert_eq!(*Phonetic::Alpha.get_associated(), 1);
+    assert_eq!(*Phonetic::Bravo.get_associated(), 2);
+    assert_eq!(*Phonetic::Charlie.get_associated(), u32::MAX);
+}
+
+#[test]
+fn generics_with_other_derives() {
+    use core::fmt::Display;
+
+    #[derive(Associated, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[associated(Type = &'static str, include_field = 0)]
+    enum Value<'a, T: Copy, const N: usize>
+    where
+        T: Display,
+    {
+        #[assoc_const("pair")]
+        Pair(T, &'a [u8; N]),
+        #[assoc(&"single")]
+        Single(T),
+    }
+
+    let pair: Value<'_, u8, 2> = Value::Pair(3, &[1, 2]);
+    let copy = pair;
+    assert_eq!(pair, copy);
+    assert_eq!(pair.clone().get_associated(), &"pair");
+    assert_eq!(
+        Value::<u8, 2>::Single(1).with_field_associated(),
+        (1, &"single")
+    );
+}
+
+#[test]
+fn const_generic_arrays() {
+    #[derive(Associated)]
+    #[associated(Type = [u8; N])]
+    enum Frame<const N: usize> {
+        #[assoc_const([0xAA; N])]
+        Header,
+        #[assoc_const({
+            let mut body = [0; N];
+            if N > 0 {
+                body[N - 1] = 1;
+            }
+            body
+        })]
+        Body,
+    }
+
+    assert_eq!(Frame::<4>::Header.get_associated(), &[0xAA; 4]);
+    assert_eq!(Frame::<3>::Body.get_associated(), &[0, 0, 1]);
+    assert_eq!(Frame::<0>::Body.get_associated(), &[]);
+}
+
+#[test]
+fn associated_with_own_variants() {
+    #[derive(Associated, Debug, PartialEq)]
+    #[associated(Type = Category)]
+    enum Category {
+        #[assoc_const(Category::Root)]
+        Root,
+        #[assoc_const(Category::Root)]
+        Animal,
+        #[assoc_const(Category::Animal)]
+        Dog,
+    }
+
+    let mut category = &Category::Dog;
+    let mut depth = 0;
+    while category != &Category::Root {
+        category = category.get_associated();
+        depth += 1;
+    }
+    assert_eq!(depth, 2);
+}
+
+#[test]
+fn attribute_placement() {
+    #[rustfmt::skip]
+    #[derive(Associated)]
+    #[associated(Type = u8)]
+    enum Placement {
+        #[assoc_const(1)]
+        Above,
+        #[assoc_const(2)] Inline,
+        #[assoc_const(3)]
+        AboveDiscriminant = 10,
+        #[assoc(&4)] InlineDiscriminant = 20,
+        #[assoc_const(5)]
+        /// Documented after the attribute.
+        #[allow(dead_code)]
+        Separated,
+    }
+
+    assert_eq!(Placement::Above.get_associated(), &1);
+    assert_eq!(Placement::Inline.get_associated(), &2);
+    assert_eq!(Placement::AboveDiscriminant.get_associated(), &3);
+    assert_eq!(Placement::InlineDiscriminant.get_associated(), &4);
+    assert_eq!(Placement::Separated.get_associated(), &5);
+    assert_eq!(Placement::Separated as u8, 21);
+}
+
+#[test]
+fn attributes_from_macros() {
+    macro_rules! table {
+        ($name:ident { $($(#[$meta:meta])* $variant:ident,)* }) => {
+            #[derive(Associated)]
+            #[associated(Type = u32)]
+            enum $name { $($(#[$meta])* $variant,)* }
+        };
+    }
+
+    macro_rules! codes {
+        ($name:ident { $($variant:ident => $value:expr,)* }) => {
+            #[derive(Associated)]
+            #[associated(Type = u32)]
+            enum $name { $(#[assoc_const($value)] $variant,)* }
+        };
+    }
+
+    table!(Forwarded {
+        #[assoc_const(1)]
+        Alpha,
+        #[cfg_attr(all(), assoc(&2))]
+        Bravo,
+    });
+    codes!(Generated {
+        Charlie => 1 + 2,
+    });
+
+    assert_eq!(Forwarded::Alpha.get_associated(), &1);
+    assert_eq!(Forwarded::Bravo.get_associated(), &2);
+    assert_eq!(Generated::Charlie.get_associated(), &3);
+}
+
+#[test]
+fn attributes_namespaced_by_association() {
+    use serde::Serialize;
+
+    #[derive(Serialize, Associated)]
+    #[associated(Type = &'static str, SecondaryType = u16)]
+    enum Service {
+        #[assoc_const("web")]
+        #[assoc_secondary_const(80)]
+        #[serde(rename = "http")]
+        Web,
+        #[assoc_secondary_const(22)]
+        #[assoc_const("shell")]
+        Shell,
+    }
+
+    assert_eq!(Service::Web.get_associated(), &"web");
+    assert_eq!(Service::Web.get_secondary(), &80);
+    assert_eq!(Service::Shell.get_associated(), &"shell");
+    assert_eq!(Service::Shell.get_secondary(), &22);
+}
+
+#[test]
+fn repr_attributes() {
+    #[derive(Associated)]
+    #[associated(Type = u16)]
+    #[repr(C)]
+    enum Flag {
+        #[assoc_const(1)]
+        Alpha = 4,
+        #[assoc_const(2)]
+        Bravo,
+    }
+
+    #[derive(Associated)]
+    #[associated(Type = u16)]
+    #[repr(C, u8)]
+    enum Tagged {
+        #[assoc_const(3)]
+        Alpha(u32),
+        #[assoc_const(4)]
+        Bravo { value: f32 },
+    }
+
+    assert_eq!(Flag::Alpha.get_associated(), &1);
+    assert_eq!(Flag::Bravo as u32, 5);
+    assert_eq!(Flag::Bravo.get_associated(), &2);
+    assert!(matches!(Tagged::Alpha(7), Tagged::Alpha(7)));
+    assert_eq!(Tagged::Alpha(7).get_associated(), &3);
+    assert!(matches!(Tagged::Bravo { value: 1.0 }, Tagged::Bravo { value } if value == 1.0));
+    assert_eq!(Tagged::Bravo { value: 1.0 }.get_associated(), &4);
+}
+
+#[test]
+fn repr_integer_type_among_other_reprs() {
+    #[derive(Associated, Debug, PartialEq)]
+    #[associated(Type = &'static str, try_from, pairs)]
+    #[repr(align(8), u8)]
+    enum Aligned {
+        #[assoc_const("low")]
+        Low = 200,
+        #[assoc_const("high")]
+        High,
+    }
+
+    #[derive(Associated, Debug, PartialEq)]
+    #[associated(Type = &'static str, try_from)]
+    #[repr(align(4))]
+    #[repr(i16)]
+    enum Split {
+        #[assoc_const("negative")]
+        Negative = -1,
+    }
+
+    #[derive(Associated, Debug, PartialEq)]
+    #[associated(Type = &'static str, try_from)]
+    #[repr(transparent)]
+    enum Only {
+        #[assoc_const("only")]
+        Only,
+    }
+
+    assert_eq!(core::mem::align_of::<Aligned>(), 8);
+    assert_eq!(Aligned::try_from(201u8), Ok(Aligned::High));
+    assert_eq!(Aligned::PAIRS, &[(200, &"low"), (201, &"high")]);
+    assert_eq!(Split::try_from(-1i16), Ok(Split::Negative));
+    assert_eq!(Only::try_from(0isize), Ok(Only::Only));
+}
+
+#[test]
+fn cfg_dependent_values() {
+    #[derive(Associated)]
+    #[associated(Type = &'static str)]
+    enum Location {
+        #[assoc_const(if cfg!(windows) { "C:\\Temp" } else { "/tmp" })]
+        Temp,
+        #[assoc_const(match (cfg!(panic = "abort"), cfg!(debug_assertions)) {
+            (true, _) => "https",
+            (false, true) => "http-debug",
+            (false, false) => "http",
+        })]
+        Scheme,
+    }
+
+    assert_eq!(
+        Location::Temp.get_associated(),
+        &if cfg!(windows) { "C:\\Temp" } else { "/tmp" }
+    );
+    assert!(Location::Scheme.get_associated().starts_with("http"));
+}
+
+#[test]
+fn assoc_macro() {
+    static LIMITS: [u32; 2] = [10, 20];
+
+    macro_rules! limit {
+        ($index:literal) => {
+            &LIMITS[$index]
+        };
+    }
+
+    #[derive(Associated)]
+    #[associated(Type = u32)]
+    enum Level {
+        #[assoc(limit!(0))]
+        Low,
+        #[assoc(limit!(1))]
+        High,
+    }
+
+    assert_eq!(Level::Low.get_associated(), &10);
+    assert!(std::ptr::eq(Level::High.get_associated(), &LIMITS[1]));
+}
+
+#[test]
+fn env_macros() {
+    #[derive(Associated)]
+    #[associated(Type = &'static str)]
+    enum Package {
+        #[assoc_const(env!("CARGO_PKG_NAME"))]
+        Name,
+        #[assoc_const(env!("CARGO_PKG_VERSION"))]
+        Version,
+    }
+
+    #[derive(Associated)]
+    #[associated(Type = Option<&'static str>)]
+    enum Build {
+        #[assoc_const(option_env!("CARGO_PKG_VERSION"))]
+        Version,
+        #[assoc_const(option_env!("ASSOCIATED_UNSET_VARIABLE"))]
+        Unset,
+    }
+
+    assert_eq!(Package::Name.get_associated(), &"associated-derive");
+    assert_eq!(
+        Build::Version.get_associated(),
+        &Some(*Package::Version.get_associated())
+    );
+    assert_eq!(Build::Unset.get_associated(), &None);
+}
+
+#[test]
+fn include_macros() {
+    #[derive(Associated)]
+    #[associated(Type = &'static str)]
+    enum Document {
+        #[assoc_const(include_str!("../README.md"))]
+        Derive,
+        #[assoc_const(include_str!("../CHANGELOG.md"))]
+        Changelog,
+    }
+
+    #[derive(Associated)]
+    #[associated(Type = &'static [u8])]
+    enum Manifest {
+        #[assoc_const(include_bytes!("../Cargo.toml"))]
+        Derive,
+    }
+
+    assert!(Document::Derive
+        .get_associated()
+        .starts_with("# associated-derive"));
+    assert!(Document::Changelog
+        .get_associated()
+        .starts_with("# `associated-derive` Changelog"));
+    assert!(Manifest::Derive.get_associated().starts_with(b"[package]"));
+}
+
+#[test]
+fn lazy_non_const_types() {
+    #[derive(Associated)]
+    #[associated(Type = Vec<u32>)]
+    enum Table {
+        #[assoc_lazy((1..=4).map(|i| i * i).collect())]
+        Squares,
+    }
+
+    assert_eq!(Table::Squares.get_associated(), &[1, 4, 9, 16]);
+}
+
+#[test]
+fn lazy_regex() {
+    use regex::Regex;
+
+    #[derive(Associated)]
+    #[associated(Type = Regex)]
+    enum Validator {
+        #[assoc_lazy(Regex::new(r"^\d{4}-\d{2}-\d{2}$").unwrap())]
+        Date,
+        #[assoc_lazy(Regex::new(r"^[a-z0-9_]{3,16}$").unwrap())]
+        Username,
+    }
+
+    let date: &'static Regex = Validator::Date.get_associated();
+    assert!(date.is_match("2024-02-29"));
+    assert!(!Validator::Username.get_associated().is_match("No Spaces"));
+}
+
+#[test]
+fn rust_decimal_values() {
+    use rust_decimal::Decimal;
+
+    #[derive(Associated)]
+    #[associated(Type = Decimal)]
+    enum Fee {
+        #[assoc_const(Decimal::from_parts(199, 0, 0, false, 2))]
+        Flat,
+        #[assoc_const(Decimal::ZERO)]
+        Waived,
+        #[assoc_lazy("0.075".parse().unwrap())]
+        Percentage,
+    }
+
+    assert_eq!(Fee::Flat.get_associated().to_string(), "1.99");
+    assert!(Fee::Waived.get_associated().is_zero());
+    assert_eq!(*Fee::Percentage.get_associated(), Decimal::new(75, 3));
+}
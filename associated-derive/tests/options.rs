@@ -0,0 +1,251 @@
+use associated::Associated;
+
+#[test]
+fn contains_range() {
+    use core::ops::RangeInclusive;
+
+    #[derive(Associated)]
+    #[associated(Type = RangeInclusive<u16>, contains)]
+    enum StatusClass {
+        #[assoc_const(200..=299)]
+        Success,
+        #[assoc_const(400..=499)]
+        ClientError,
+    }
+
+    assert!(StatusClass::Success.associated_contains(204));
+    assert!(!StatusClass::ClientError.associated_contains(500));
+    assert_eq!(StatusClass::ClientError.get_associated().start(), &400);
+}
+
+#[test]
+fn rename_all() {
+    #[derive(Associated)]
+    #[associated(Type = &'static str, rename_all = "kebab-case")]
+    enum Setting {
+        MaxConnections,
+        #[assoc_const("timeout")]
+        RequestTimeout,
+    }
+
+    #[derive(Associated)]
+    #[associated(Type = &'static str, rename_all = "SCREAMING_SNAKE_CASE")]
+    enum EnvVar {
+        LogLevel,
+    }
+
+    #[derive(Associated)]
+    #[associated(Type = &'static str, rename_all = "snake_case")]
+    enum Column {
+        CreatedAt,
+    }
+
+    #[derive(Associated)]
+    #[associated(Type = &'static str, rename_all = "UPPERCASE")]
+    enum Method {
+        Get,
+    }
+
+    #[derive(Associated)]
+    #[associated(Type = &'static str, rename_all = "camelCase")]
+    enum Field {
+        UserName,
+    }
+
+    assert_eq!(Setting::MaxConnections.get_associated(), &"max-connections");
+    assert_eq!(Setting::RequestTimeout.get_associated(), &"timeout");
+    assert_eq!(EnvVar::LogLevel.get_associated(), &"LOG_LEVEL");
+    assert_eq!(Column::CreatedAt.get_associated(), &"created_at");
+    assert_eq!(Method::Get.get_associated(), &"GET");
+    assert_eq!(Field::UserName.get_associated(), &"userName");
+}
+
+#[test]
+fn prefix_and_suffix() {
+    #[derive(Associated)]
+    #[associated(Type = &'static str, rename_all = "snake_case", prefix = "cmd_")]
+    enum Command {
+        Alpha,
+        ListAll,
+        #[assoc_const("quit")]
+        Exit,
+    }
+
+    #[derive(Associated)]
+    #[associated(Type = &'static str, suffix = ".json")]
+    enum File {
+        Config,
+    }
+
+    #[derive(Associated)]
+    #[associated(Type = &'static str, prefix = "--", suffix = "=", rename_all = "kebab-case")]
+    enum Flag {
+        DryRun,
+    }
+
+    assert_eq!(Command::Alpha.get_associated(), &"cmd_alpha");
+    assert_eq!(Command::ListAll.get_associated(), &"cmd_list_all");
+    assert_eq!(Command::Exit.get_associated(), &"quit");
+    assert_eq!(File::Config.get_associated(), &"Config.json");
+    assert_eq!(Flag::DryRun.get_associated(), &"--dry-run=");
+}
+
+#[test]
+fn expose_consts_referenced_by_values() {
+    #[derive(Associated)]
+    #[associated(Type = u32, expose_consts)]
+    enum Phonetic {
+        #[assoc_const(Phonetic::ALPHA_ASSOCIATED * 2)]
+        Bravo,
+        #[assoc_const(1)]
+        Alpha,
+        #[assoc(&Phonetic::BRAVO_ASSOCIATED)]
+        Charlie,
+    }
+
+    assert_eq!(Phonetic::Alpha.get_associated(), &1);
+    assert_eq!(Phonetic::Bravo.get_associated(), &2);
+    assert_eq!(Phonetic::BRAVO_ASSOCIATED, 2);
+    assert_eq!(Phonetic::Charlie.get_associated(), &2);
+}
+
+#[test]
+fn via_overridden() {
+    #[derive(Associated)]
+    #[associated(Type = u16, via = default_port)]
+    enum Service {
+        Http,
+        #[assoc_const(8443)]
+        Https,
+        Ssh,
+        #[assoc(&2222)]
+        SshAlt,
+    }
+
+    const fn default_port(service: Service) -> u16 {
+        match service {
+            Service::Http => 80,
+            Service::Https => 443,
+            Service::Ssh | Service::SshAlt => 22,
+        }
+    }
+
+    assert_eq!(*Service::Http.get_associated(), 80);
+    assert_eq!(*Service::Https.get_associated(), 8443);
+    assert_eq!(*Service::Ssh.get_associated(), 22);
+    assert_eq!(*Service::SshAlt.get_associated(), 2222);
+}
+
+#[test]
+fn from_associated_sorted() {
+    #[derive(Associated, Debug, PartialEq)]
+    #[associated(Type = &'static str, from_associated)]
+    enum Element {
+        #[assoc_const("oxygen")]
+        Oxygen,
+        #[assoc_const("carbon")]
+        Carbon,
+        #[assoc(&"hydrogen")]
+        Hydrogen,
+        #[assoc_const("carbon")]
+        Graphite,
+    }
+
+    assert_eq!(
+        Element::SORTED,
+        [
+            ("carbon", Element::Carbon),
+            ("hydrogen", Element::Hydrogen),
+            ("oxygen", Element::Oxygen)
+        ],
+    );
+    assert_eq!(Element::from_associated(&"oxygen"), Some(Element::Oxygen));
+    assert_eq!(Element::from_associated(&"carbon"), Some(Element::Carbon));
+    assert_eq!(Element::from_associated(&"helium"), None);
+}
+
+#[test]
+fn all_from_associated() {
+    #[derive(Associated, Debug, PartialEq)]
+    #[associated(Type = u8, from_associated)]
+    enum Color {
+        #[assoc_const(0)]
+        Black,
+        #[assoc_const(7)]
+        Gray,
+        #[assoc_const(7)]
+        Grey,
+    }
+
+    assert_eq!(Color::from_associated(&7), Some(Color::Gray));
+    assert_eq!(Color::all_from_associated(&7), [Color::Gray, Color::Grey]);
+    assert!(Color::all_from_associated(&1).is_empty());
+}
+
+#[test]
+fn from_any_associated() {
+    #[derive(Associated, Debug, PartialEq)]
+    #[associated(Type = &'static [&'static str], from_associated)]
+    enum Phonetic {
+        #[assoc_const(&["a", "alpha"])]
+        Alpha,
+        #[assoc_const(&["b", "bravo"])]
+        Bravo,
+    }
+
+    assert_eq!(
+        Phonetic::from_any_associated("alpha"),
+        Some(Phonetic::Alpha)
+    );
+    assert_eq!(Phonetic::from_any_associated("b"), Some(Phonetic::Bravo));
+    assert_eq!(Phonetic::from_any_associated("c"), None);
+}
+#[test]
+fn eq_value_integer() {
+    #[derive(Associated)]
+    #[associated(Type = u16, eq_value)]
+    enum Port {
+        #[assoc_const(80)]
+        Http,
+    }
+
+    assert!(Port::Http == 80);
+    assert!(Port::Http != 443);
+}
+
+#[test]
+fn try_from_without_repr() {
+    #[derive(Associated, Debug, PartialEq)]
+    #[associated(Type = &'static str, try_from)]
+    enum Offset {
+        #[assoc_const("back")]
+        Back = -1,
+        #[assoc_const("forward")]
+        Forward = 1,
+    }
+
+    assert_eq!(
+        Offset::try_from(-1isize).map(|o| *o.get_associated()),
+        Ok("back")
+    );
+    assert_eq!(Offset::try_from(1isize), Ok(Offset::Forward));
+    assert_eq!(Offset::try_from(0isize), Err(0));
+}
+
+#[test]
+fn sequence_cycle() {
+    #[derive(Associated, Debug, PartialEq)]
+    #[associated(Type = &'static str, sequence = cycle)]
+    enum Light {
+        #[assoc_const("red")]
+        Red,
+        #[assoc_const("green")]
+        Green,
+        #[assoc_const("amber")]
+        Amber,
+    }
+
+    assert_eq!(Light::Red.next_variant(), Light::Green);
+    assert_eq!(Light::Amber.next_variant(), Light::Red);
+    assert_eq!(Light::Red.prev_variant(), Light::Amber);
+}
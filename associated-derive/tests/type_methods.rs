@@ -0,0 +1,54 @@
+use associated::Associated;
+
+#[test]
+fn associated_bytes() {
+    #[derive(Associated)]
+    #[associated(Type = &'static str)]
+    enum Command {
+        #[assoc_const("PING\r\n")]
+        Ping,
+        #[assoc(&"QUIT\r\n")]
+        Quit,
+    }
+
+    assert_eq!(Command::Ping.associated_bytes(), b"PING\r\n");
+    assert_eq!(
+        Command::Quit.associated_bytes(),
+        [b'Q', b'U', b'I', b'T', b'\r', b'\n']
+    );
+}
+
+#[test]
+fn path_named_type() {
+    mod http {
+        pub struct Path(pub &'static str);
+    }
+
+    #[derive(Associated)]
+    #[associated(Type = &'static http::Path)]
+    enum Route {
+        #[assoc_const(&http::Path("/"))]
+        Root,
+    }
+
+    assert_eq!(Route::Root.get_associated().0, "/");
+}
+
+#[test]
+fn atomic_ordering() {
+    use core::sync::atomic::{self, AtomicBool};
+
+    #[derive(Associated)]
+    #[associated(Type = atomic::Ordering)]
+    enum Access {
+        #[assoc_const(atomic::Ordering::Relaxed)]
+        Counter,
+        #[assoc_const(atomic::Ordering::SeqCst)]
+        Flag,
+    }
+
+    assert_eq!(Access::Counter.get_associated(), &atomic::Ordering::Relaxed);
+    let flag = AtomicBool::new(false);
+    flag.store(true, *Access::Flag.get_associated());
+    assert!(flag.load(*Access::Flag.get_associated()));
+}
@@ -0,0 +1,9 @@
+use associated::Associated;
+
+#[derive(Associated)]
+#[associated(Type = u8, all_variants)]
+enum Level {
+    #[assoc_const(1)] Low,
+}
+
+fn main() {}
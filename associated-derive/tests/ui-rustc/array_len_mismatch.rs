@@ -0,0 +1,9 @@
+use associated::Associated;
+
+#[derive(Associated)]
+#[associated(Type = &'static [u8; 4])]
+enum Header {
+    #[assoc_const(b"RIF")] Riff,
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+use associated::{assert_all_associated, Associated};
+
+#[derive(Associated)]
+#[associated(Type = u8)]
+enum Level {
+    #[assoc_const(0)] Low,
+    #[assoc_const(1)] High,
+    #[assoc_const(2)] Max,
+}
+
+assert_all_associated!(Level, [Low, High]);
+
+enum Plain {
+    Low,
+}
+
+assert_all_associated!(Plain, [Low]);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use associated::Associated;
+
+#[derive(Associated)]
+#[associated(Type = u16)]
+enum Opcode {
+    #[assoc_const(unreachable!())] Legacy,
+}
+
+fn main() {}
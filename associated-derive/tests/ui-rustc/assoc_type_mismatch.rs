@@ -0,0 +1,9 @@
+use associated::Associated;
+
+#[derive(Associated)]
+#[associated(Type = &'static str)]
+enum Phonetic {
+    #[assoc("Alpha")] Alpha,
+}
+
+fn main() {}
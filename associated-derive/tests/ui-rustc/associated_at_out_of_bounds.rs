@@ -0,0 +1,12 @@
+use associated::Associated;
+
+#[derive(Associated)]
+#[associated(Type = &'static str, associated_at)]
+enum Level {
+    #[assoc_const("debug")] Debug,
+    #[assoc_const("info")] Info,
+}
+
+const MISSING: &str = Level::associated_at(2);
+
+fn main() {}
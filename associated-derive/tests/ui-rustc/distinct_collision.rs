@@ -0,0 +1,25 @@
+use associated::Associated;
+
+#[derive(Associated)]
+#[associated(Type = u8, distinct)]
+enum Code {
+    #[assoc_const(1)] Alpha,
+    #[assoc_const(1)] Bravo,
+}
+
+#[derive(Associated)]
+#[associated(Type = &'static str, distinct)]
+enum Key {
+    #[assoc_const("alpha")] Alpha,
+    #[assoc_const("bravo")] Bravo,
+    #[assoc(&"alpha")] Charlie,
+}
+
+#[derive(Associated)]
+#[associated(Type = (u8, u8), distinct)]
+enum Pair {
+    #[assoc_const((1, 2))] Alpha,
+    #[assoc_const((1, 2))] Bravo,
+}
+
+fn main() {}
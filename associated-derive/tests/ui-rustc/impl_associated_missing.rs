@@ -0,0 +1,10 @@
+use associated::impl_associated;
+
+enum Phonetic {
+    Alpha,
+    Bravo,
+}
+
+impl_associated!(Phonetic, { Alpha => "Alpha" });
+
+fn main() {}
@@ -0,0 +1,10 @@
+use associated::Associated;
+use std::sync::Mutex;
+
+#[derive(Associated)]
+#[associated(Type = Mutex<u32>)]
+enum Operation {
+    #[assoc_const(Mutex::new(0))] Read,
+}
+
+fn main() {}
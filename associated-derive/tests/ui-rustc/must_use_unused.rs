@@ -0,0 +1,22 @@
+#![deny(unused_must_use)]
+
+use associated::Associated;
+
+#[derive(Associated)]
+#[associated(Type = &'static str, must_use, from_associated)]
+enum Permission {
+    #[assoc_const("read")] Read,
+}
+
+fn main() {
+    Permission::Read.get_associated();
+    Permission::Read.associated_len();
+    Permission::all_from_associated(&"read");
+}
+
+fn generic<T: Associated>(value: T)
+where
+    T::AssociatedType: 'static,
+{
+    value.get_associated();
+}
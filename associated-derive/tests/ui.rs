@@ -0,0 +1,10 @@
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+    // These pin rustc's own diagnostics, which change between releases, so they're only checked on
+    // the toolchain pinned in CI.
+    if std::env::var_os("ASSOCIATED_UI_RUSTC").is_some() {
+        t.compile_fail("tests/ui-rustc/*.rs");
+    }
+}
@@ -0,0 +1,10 @@
+use associated::Associated;
+
+#[derive(Associated)]
+#[associated(Type = u8, associated_at)]
+enum Level {
+    #[assoc_const(1)] Low = 1,
+    #[assoc_const(2)] High,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use associated::Associated;
+
+#[derive(Associated)]
+#[cfg_attr(any(), associated(Type = u16))]
+enum Service {
+    #[assoc_const(80)] Http,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use associated::AssociatedValue;
+
+#[derive(AssociatedValue)]
+#[associated(Type = u8, distinct)]
+enum Code {
+    #[assoc_const(1)] Alpha,
+    #[assoc_const(1)] Bravo,
+}
+
+#[derive(AssociatedValue)]
+#[associated_value(Type = &'static str, names)]
+enum Phonetic {
+    #[assoc_const("Alpha")] Alpha,
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+use associated::{Associated, AssociatedWith};
+
+struct Limits {
+    global: u32,
+}
+
+#[derive(AssociatedWith)]
+#[associated_with(Type = u32)]
+enum MissingContext {
+    #[assoc_with(&ctx.global)] Free,
+}
+
+#[derive(AssociatedWith)]
+#[associated_with(Type = u32, Context = Limits, names)]
+enum UnsupportedOption {
+    #[assoc_with(&ctx.global)] Free,
+}
+
+#[derive(Associated)]
+#[associated(Type = u32, Context = Limits)]
+enum ContextWithoutAssociatedWith {
+    #[assoc_const(1)] Free,
+}
+
+fn main() {}
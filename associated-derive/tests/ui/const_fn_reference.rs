@@ -0,0 +1,16 @@
+use associated::Associated;
+
+#[derive(Associated)]
+#[associated(Type = u16, const_fn)]
+enum Port {
+    #[assoc_const(80)] Http,
+    #[assoc(&443)] Https,
+}
+
+#[derive(Associated)]
+#[associated(Type = String, const_fn)]
+enum Greeting {
+    #[assoc_lazy(String::from("hello"))] Hello,
+}
+
+fn main() {}
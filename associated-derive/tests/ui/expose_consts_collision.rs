@@ -0,0 +1,11 @@
+use associated::Associated;
+
+#[derive(Associated)]
+#[associated(Type = u32, expose_consts)]
+#[allow(non_camel_case_types)]
+enum Phonetic {
+    #[assoc_const(1)] AlphaBravo,
+    #[assoc_const(2)] Alpha_Bravo,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use associated::Associated;
+
+#[derive(Associated)]
+#[associated(Type = &'static str, from_doc)]
+enum Command {
+    Run,
+}
+
+fn main() {}
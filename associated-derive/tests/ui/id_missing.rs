@@ -0,0 +1,10 @@
+use associated::Associated;
+
+#[derive(Associated)]
+#[associated(Type = &'static str, id = u32)]
+enum Phonetic {
+    #[assoc_pair("Alpha", 1)] Alpha,
+    #[assoc_const("Bravo")] Bravo,
+}
+
+fn main() {}
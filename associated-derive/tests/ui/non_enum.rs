@@ -0,0 +1,19 @@
+use associated::Associated;
+
+#[derive(Associated)]
+#[associated(Type = u8)]
+struct Wrapper(u8);
+
+#[derive(Associated)]
+struct Pair(u8, u16);
+
+#[derive(Associated)]
+struct Marker;
+
+#[derive(Associated)]
+union Bits {
+    int: u32,
+    float: f32,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use associated::Associated;
+
+#[derive(Associated)]
+#[associated(Type = &'static str, predicates)]
+enum Server {
+    #[assoc_const("a")] HttpServer,
+    #[assoc_const("b")] HTTPServer,
+}
+
+fn main() {}
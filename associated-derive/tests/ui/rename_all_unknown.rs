@@ -0,0 +1,9 @@
+use associated::Associated;
+
+#[derive(Associated)]
+#[associated(Type = &'static str, rename_all = "Title Case")]
+enum Setting {
+    MaxConnections,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use associated::Associated;
+
+#[derive(Associated)]
+#[associated(Type = &'static str, SecondaryType = u32)]
+enum Phonetic {
+    #[assoc_const("Alpha")]
+    Alpha,
+}
+
+fn main() {}
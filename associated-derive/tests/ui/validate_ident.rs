@@ -0,0 +1,28 @@
+use associated::Associated;
+
+#[derive(Associated)]
+#[associated(Type = &'static str, validate = ident)]
+enum Field {
+    #[assoc_const("user_id")] UserId,
+    #[assoc_const("user-name")] UserName,
+}
+
+#[derive(Associated)]
+#[associated(Type = &'static str, validate = ident)]
+enum Padded {
+    #[assoc_const(" user_id\n")] UserId,
+}
+
+#[derive(Associated)]
+#[associated(Type = &'static str, validate = ident)]
+enum BlockComment {
+    #[assoc_const("foo /* x */")] Foo,
+}
+
+#[derive(Associated)]
+#[associated(Type = &'static str, validate = ident)]
+enum LineComment {
+    #[assoc_const("bar // c")] Bar,
+}
+
+fn main() {}
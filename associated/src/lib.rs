@@ -1,12 +1,143 @@
 //! A trait to associate enum variants with constants. See [associated-derive](https://docs.rs/associated-derive) for deriving this trait automatically.
 //!
-//! Derive support is enabled with the `"derive"` feature.
+//! Derive support is enabled with the `"derive"` feature. The `"std"` feature enables `#[assoc_lazy]`
+//! when deriving, and the `"alloc"` feature, which `"std"` implies, enables functions returning
+//! collections. The `"clap"` feature enables deriving `clap::ValueEnum`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 #[cfg(feature = "derive")]
 pub use associated_derive::*;
 
+/// Support for code generated by associated-derive. Not public API.
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "std")]
+    pub use std::sync::OnceLock;
+
+    #[cfg(feature = "alloc")]
+    pub use alloc::vec::Vec;
+
+    #[cfg(feature = "clap")]
+    pub use clap;
+
+    pub use crate::__if_alloc as if_alloc;
+
+    /// Compares strings in const contexts, where `==` isn't available.
+    pub const fn str_eq(a: &str, b: &str) -> bool {
+        let (a, b) = (a.as_bytes(), b.as_bytes());
+        if a.len() != b.len() {
+            return false;
+        }
+        let mut i = 0;
+        while i < a.len() {
+            if a[i] != b[i] {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Converts the value of an `#[assoc_lazy]` expression into the associated type.
+    pub trait IntoLazy<T> {
+        fn into_lazy(self) -> T;
+    }
+
+    impl<T> IntoLazy<T> for T {
+        fn into_lazy(self) -> T {
+            self
+        }
+    }
+
+    /// Lets `#[assoc_lazy]` associate a `&'static str` with a formatted `String`, which lives as long
+    /// as the program once initialized.
+    #[cfg(feature = "std")]
+    impl IntoLazy<&'static str> for std::string::String {
+        fn into_lazy(self) -> &'static str {
+            self.leak()
+        }
+    }
+}
+
+/// Expands to the given items if the `alloc` feature is enabled, so generated code can depend on it.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __if_alloc {
+    ($($item:tt)*) => {
+        $($item)*
+    };
+}
+
+/// Expands to the given items if the `alloc` feature is enabled, so generated code can depend on it.
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __if_alloc {
+    ($($item:tt)*) => {};
+}
+
 /// See [`associated-derive`] for deriving this trait automatically.
 /// 
+/// Generic code over enums sharing an associated type can bound on `Associated<AssociatedType = T>`,
+/// or on the shorter [`AssociatedSame<T>`], which every such enum implements.
+///
+/// ```rust
+/// use associated::AssociatedSame;
+/// # use associated_derive::Associated;
+///
+/// #[derive(Associated)]
+/// #[associated(Type = u16)]
+/// enum Protocol {
+///     #[assoc_const(80)] Http,
+/// }
+///
+/// #[derive(Associated)]
+/// #[associated(Type = u16)]
+/// enum Version {
+///     #[assoc_const(0x0101)] V1_1,
+/// }
+///
+/// fn encode<A: associated::Associated<AssociatedType = u16>>(a: &A) -> u16 {
+///     *a.get_associated()
+/// }
+///
+/// fn encode_same<A: AssociatedSame<u16>>(a: &A) -> u16 {
+///     *a.get()
+/// }
+///
+/// assert_eq!(encode(&Protocol::Http), 80);
+/// assert_eq!(encode(&Version::V1_1), 0x0101);
+/// assert_eq!(encode_same(&Version::V1_1), 0x0101);
+/// ```
+///
+/// Such enums can also be used as `dyn Associated<AssociatedType = T>` trait objects.
+///
+/// ```rust
+/// use associated::Associated;
+/// # use associated_derive::Associated as DeriveAssociated;
+///
+/// #[derive(DeriveAssociated)]
+/// #[associated(Type = u8)]
+/// enum Low {
+///     #[assoc_const(1)] One,
+/// }
+///
+/// #[derive(DeriveAssociated)]
+/// #[associated(Type = u8)]
+/// enum High {
+///     #[assoc_const(200)] TwoHundred,
+/// }
+///
+/// let values: [&dyn Associated<AssociatedType = u8>; 2] = [&Low::One, &High::TwoHundred];
+/// let total: u32 = values.iter().map(|v| u32::from(*v.get_associated())).sum();
+/// assert_eq!(total, 201);
+/// ```
+///
 /// [`associated-derive`]: https://docs.rs/associated-derive
 pub trait Associated {
     /// The type of the constants associated with this enum.
@@ -16,11 +147,453 @@ pub trait Associated {
     /// Returns a static lifetime reference to the constant associated with this variant.
     /// 
     /// If derived with associated-derive, this will be the argument to `#[assoc]` or `#[assoc_const]`
+    #[must_use]
     fn get_associated(&self) -> &'static Self::AssociatedType;
+    /// Returns this variant together with its associated constant.
+    ///
+    /// ```rust
+    /// use associated::Associated as _;
+    /// # use associated_derive::Associated;
+    ///
+    /// #[derive(Associated, Debug, PartialEq)]
+    /// #[associated(Type = u16)]
+    /// enum Protocol {
+    ///     #[assoc_const(80)] Http,
+    ///     #[assoc_const(443)] Https,
+    /// }
+    ///
+    /// let pairs: Vec<_> = [Protocol::Http, Protocol::Https].into_iter().map(Protocol::with_associated).collect();
+    /// assert_eq!(pairs, [(Protocol::Http, &80), (Protocol::Https, &443)]);
+    /// ```
+    fn with_associated(self) -> (Self, &'static Self::AssociatedType)
+    where
+        Self: Sized,
+    {
+        let associated = self.get_associated();
+        (self, associated)
+    }
+    /// Returns the result of calling `f` with the constant associated with this variant.
+    ///
+    /// ```rust
+    /// use associated::Associated as _;
+    /// # use associated_derive::Associated;
+    ///
+    /// #[derive(Associated)]
+    /// #[associated(Type = u32)]
+    /// enum Side {
+    ///     #[assoc_const(3)] Small,
+    ///     #[assoc_const(12)] Large,
+    /// }
+    ///
+    /// assert_eq!(Side::Small.map_associated(|side| side * side), 9);
+    /// assert_eq!(Side::Large.map_associated(|side| side * side), 144);
+    /// ```
+    fn map_associated<R>(&self, f: impl FnOnce(&'static Self::AssociatedType) -> R) -> R
+    where
+        Self: Sized,
+        Self::AssociatedType: 'static,
+    {
+        f(self.get_associated())
+    }
+    /// Returns whether the constant associated with this variant is one of `set`.
+    ///
+    /// ```rust
+    /// use associated::Associated as _;
+    /// # use associated_derive::Associated;
+    ///
+    /// #[derive(Associated)]
+    /// #[associated(Type = u16)]
+    /// enum Status {
+    ///     #[assoc_const(200)] Ok,
+    ///     #[assoc_const(404)] NotFound,
+    ///     #[assoc_const(503)] Unavailable,
+    /// }
+    ///
+    /// const RETRYABLE: &[u16] = &[502, 503, 504];
+    /// assert!(Status::Unavailable.associated_in(RETRYABLE));
+    /// assert!(!Status::NotFound.associated_in(RETRYABLE));
+    /// assert!(!Status::Ok.associated_in(&[]));
+    /// ```
+    fn associated_in(&self, set: &[Self::AssociatedType]) -> bool
+    where
+        Self::AssociatedType: PartialEq + 'static,
+    {
+        let associated = self.get_associated();
+        set.iter().any(|x| x == associated)
+    }
+    /// Returns the constant associated with this variant formatted with `Display`.
+    ///
+    /// Requires the `alloc` feature.
+    ///
+    /// ```rust
+    /// use associated::Associated as _;
+    /// # use associated_derive::Associated;
+    ///
+    /// #[derive(Associated)]
+    /// #[associated(Type = f32)]
+    /// enum Threshold {
+    ///     #[assoc_const(0.5)] Warn,
+    ///     #[assoc_const(0.9)] Critical,
+    /// }
+    ///
+    /// assert_eq!(Threshold::Warn.associated_to_string(), "0.5");
+    /// assert_eq!(format!("at {}", Threshold::Critical.associated_to_string()), "at 0.9");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn associated_to_string(&self) -> alloc::string::String
+    where
+        Self::AssociatedType: core::fmt::Display + 'static,
+    {
+        alloc::string::ToString::to_string(self.get_associated())
+    }
+    /// Returns the constant associated with `this`, if it's a variant, for chains where one may be
+    /// absent.
+    ///
+    /// ```rust
+    /// use associated::Associated as _;
+    /// # use associated_derive::Associated;
+    ///
+    /// #[derive(Associated)]
+    /// #[associated(Type = &'static str)]
+    /// enum Unit {
+    ///     #[assoc_const("kg")] Kilogram,
+    ///     #[assoc_const("m")] Metre,
+    /// }
+    ///
+    /// assert_eq!(Unit::get_associated_opt(Some(&Unit::Metre)), Some(&"m"));
+    /// assert_eq!(Unit::get_associated_opt(None), None);
+    /// ```
+    fn get_associated_opt(this: Option<&Self>) -> Option<&'static Self::AssociatedType>
+    where
+        Self: Sized,
+    {
+        this.map(Self::get_associated)
+    }
+    /// Returns the constant associated with this variant as `dyn Any`, so values of different
+    /// enums can be stored together and downcast.
+    ///
+    /// ```rust
+    /// use associated::Associated as _;
+    /// # use associated_derive::Associated;
+    /// use std::any::Any;
+    ///
+    /// #[derive(Associated)]
+    /// #[associated(Type = u16)]
+    /// enum Port {
+    ///     #[assoc_const(443)] Https,
+    /// }
+    ///
+    /// #[derive(Associated)]
+    /// #[associated(Type = &'static str)]
+    /// enum Host {
+    ///     #[assoc_const("localhost")] Local,
+    /// }
+    ///
+    /// let values: [&'static dyn Any; 2] = [Port::Https.get_associated_any(), Host::Local.get_associated_any()];
+    /// assert_eq!(values[0].downcast_ref::<u16>(), Some(&443));
+    /// assert_eq!(values[1].downcast_ref::<&str>(), Some(&"localhost"));
+    /// assert_eq!(values[1].downcast_ref::<u16>(), None);
+    /// ```
+    fn get_associated_any(&self) -> &'static dyn core::any::Any
+    where
+        Self::AssociatedType: core::any::Any,
+    {
+        self.get_associated()
+    }
+    /// Feeds the constant associated with this variant into `state`, such as for a cache key, without
+    /// changing how the enum itself is hashed.
+    ///
+    /// ```rust
+    /// use associated::Associated as _;
+    /// # use associated_derive::Associated;
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::{Hash, Hasher};
+    ///
+    /// #[derive(Associated)]
+    /// #[associated(Type = &'static str)]
+    /// enum Endpoint {
+    ///     #[assoc_const("/users")] Users,
+    ///     #[assoc_const("/users")] Members,
+    ///     #[assoc_const("/posts")] Posts,
+    /// }
+    ///
+    /// fn key(endpoint: Endpoint) -> u64 {
+    ///     let mut hasher = DefaultHasher::new();
+    ///     endpoint.associated_hash(&mut hasher);
+    ///     hasher.finish()
+    /// }
+    ///
+    /// let mut hasher = DefaultHasher::new();
+    /// "/users".hash(&mut hasher);
+    /// assert_eq!(key(Endpoint::Users), hasher.finish());
+    /// assert_eq!(key(Endpoint::Users), key(Endpoint::Members));
+    /// assert_ne!(key(Endpoint::Users), key(Endpoint::Posts));
+    /// ```
+    fn associated_hash<H: core::hash::Hasher>(&self, state: &mut H)
+    where
+        Self: Sized,
+        Self::AssociatedType: core::hash::Hash + 'static,
+    {
+        core::hash::Hash::hash(self.get_associated(), state)
+    }
+}
+
+/// Returns the first of `items` whose associated constant satisfies `pred`.
+///
+/// ```rust
+/// use associated::find_by_associated;
+/// # use associated_derive::Associated;
+///
+/// #[derive(Associated, Debug, PartialEq)]
+/// #[associated(Type = u32)]
+/// enum Tier {
+///     #[assoc_const(10)] Bronze,
+///     #[assoc_const(50)] Silver,
+///     #[assoc_const(100)] Gold,
+/// }
+///
+/// let tiers = [Tier::Bronze, Tier::Silver, Tier::Gold];
+/// assert_eq!(find_by_associated(&tiers, |points| *points > 20), Some(&Tier::Silver));
+/// assert_eq!(find_by_associated(&tiers, |points| *points > 200), None);
+/// ```
+pub fn find_by_associated<T, F>(items: &[T], pred: F) -> Option<&T>
+where
+    T: Associated,
+    T::AssociatedType: 'static,
+    F: Fn(&T::AssociatedType) -> bool,
+{
+    items.iter().find(|item| pred(item.get_associated()))
+}
+
+/// Returns an iterator over the associated constants of `items`, in order.
+///
+/// ```rust
+/// use associated::associated_iter_of;
+/// # use associated_derive::Associated;
+///
+/// #[derive(Associated)]
+/// #[associated(Type = u16)]
+/// enum Protocol {
+///     #[assoc_const(80)] Http,
+///     #[assoc_const(443)] Https,
+/// }
+///
+/// let total: u16 = associated_iter_of(&[Protocol::Http, Protocol::Https]).sum();
+/// assert_eq!(total, 523);
+/// ```
+pub fn associated_iter_of<T>(items: &[T]) -> impl Iterator<Item = &'static T::AssociatedType> + '_
+where
+    T: Associated,
+    T::AssociatedType: 'static,
+{
+    items.iter().map(|item| item.get_associated())
+}
+
+/// Collects the associated constants of `items`, in order. Requires the `"alloc"` feature.
+///
+/// ```rust
+/// use associated::associated_all_of;
+/// # use associated_derive::Associated;
+///
+/// #[derive(Associated)]
+/// #[associated(Type = &'static str)]
+/// enum Phonetic {
+///     #[assoc_const("Alpha")] Alpha,
+///     #[assoc_const("Bravo")] Bravo,
+/// }
+///
+/// let names = associated_all_of(&[Phonetic::Bravo, Phonetic::Alpha, Phonetic::Bravo]);
+/// assert_eq!(names, [&"Bravo", &"Alpha", &"Bravo"]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn associated_all_of<T>(items: &[T]) -> alloc::vec::Vec<&'static T::AssociatedType>
+where
+    T: Associated,
+    T::AssociatedType: 'static,
+{
+    associated_iter_of(items).collect()
 }
 
+/// Object-safe view of [`Associated`] for a known associated type `V`.
+///
+/// Every implementor of `Associated<AssociatedType = V>` implements `AssociatedSame<V>`, so different
+/// enums sharing an associated type can be stored and used together as `dyn AssociatedSame<V>`.
+///
+/// ```rust
+/// use associated::AssociatedSame;
+/// # use associated_derive::Associated;
+///
+/// #[derive(Associated)]
+/// #[associated(Type = u16)]
+/// enum Protocol {
+///     #[assoc_const(80)] Http,
+///     #[assoc_const(443)] Https,
+/// }
+///
+/// #[derive(Associated)]
+/// #[associated(Type = u16)]
+/// enum Service {
+///     #[assoc_const(22)] Ssh,
+/// }
+///
+/// let ports: [&dyn AssociatedSame<u16>; 3] = [&Protocol::Http, &Protocol::Https, &Service::Ssh];
+/// let ports: Vec<u16> = ports.iter().map(|p| *p.get()).collect();
+/// assert_eq!(ports, [80, 443, 22]);
+/// ```
+pub trait AssociatedSame<V: 'static> {
+    /// Returns a static lifetime reference to the constant associated with this variant.
+    fn get(&self) -> &'static V;
+}
+
+impl<T, V: 'static> AssociatedSame<V> for T
+where
+    T: Associated<AssociatedType = V> + ?Sized,
+{
+    fn get(&self) -> &'static V {
+        self.get_associated()
+    }
+}
+
+/// Like [`Associated`], but returns associated values by value instead of by `'static` reference.
+///
+/// This suits associated types that can't be placed behind a `'static` reference, such as types
+/// with destructors. See [`associated-derive`] for deriving this trait automatically.
+///
+/// [`associated-derive`]: https://docs.rs/associated-derive
+pub trait AssociatedValue {
+    /// The type of the values associated with this enum.
+    ///
+    /// If derived with associated-derive, this will be whatever `Type` is assigned to in `#[associated]`
+    type AssociatedType;
+    /// Returns the value associated with this variant.
+    ///
+    /// If derived with associated-derive, values from `#[assoc_const]` are returned as is and values
+    /// from `#[assoc]` are cloned out of their reference.
+    fn associated_value(&self) -> Self::AssociatedType;
+}
+
+/// Like [`Associated`], but borrows associated values from a context of type `Ctx` instead of
+/// returning `'static` references.
+///
+/// This suits values that can't live for `'static`, such as per-thread or per-request configuration.
+/// See [`associated-derive`] for deriving this trait automatically.
+///
+/// ```rust
+/// use associated::AssociatedWith as _;
+/// # use associated_derive::AssociatedWith;
+///
+/// struct Config {
+///     greeting: String,
+///     farewell: String,
+/// }
+///
+/// #[derive(AssociatedWith)]
+/// #[associated_with(Type = str, Context = Config)]
+/// enum Message {
+///     #[assoc_with(&ctx.greeting)] Hello,
+///     #[assoc_with(&ctx.farewell)] Goodbye,
+/// }
+///
+/// let config = Config { greeting: "hi".to_string(), farewell: "bye".to_string() };
+/// assert_eq!(Message::Hello.get_associated_with(&config), "hi");
+/// assert_eq!(Message::Goodbye.get_associated_with(&config), "bye");
+/// ```
+///
+/// [`associated-derive`]: https://docs.rs/associated-derive
+pub trait AssociatedWith<Ctx: ?Sized> {
+    /// The type of the values associated with this enum.
+    ///
+    /// If derived with associated-derive, this will be whatever `Type` is assigned to in
+    /// `#[associated_with]`
+    type AssociatedType: ?Sized;
+    /// Returns a reference to the value associated with this variant, borrowed from `ctx`.
+    ///
+    /// If derived with associated-derive, this will be the argument to `#[assoc_with]`
+    fn get_associated_with<'a>(&self, ctx: &'a Ctx) -> &'a Self::AssociatedType;
+}
+
+/// The error of a derived `FromStr` implementation, for a string that isn't associated with any
+/// variant. Its `Display` lists the strings that are.
+///
+/// ```rust
+/// use associated::ParseAssociatedError;
+/// # use associated_derive::Associated;
+///
+/// #[derive(Associated, Debug, PartialEq)]
+/// #[associated(Type = &'static str, from_str)]
+/// enum Format {
+///     #[assoc_const("json")] Json,
+///     #[assoc_const("yaml")] Yaml,
+/// }
+///
+/// assert_eq!("yaml".parse(), Ok(Format::Yaml));
+/// let error: ParseAssociatedError = "toml".parse::<Format>().unwrap_err();
+/// assert_eq!(error.expected(), ["json", "yaml"]);
+/// assert_eq!(error.to_string(), "expected one of: `json`, `yaml`");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseAssociatedError {
+    expected: &'static [&'static str],
+}
+
+impl ParseAssociatedError {
+    /// Creates an error listing the `expected` strings.
+    pub const fn new(expected: &'static [&'static str]) -> Self {
+        Self { expected }
+    }
+    /// Returns the strings that are associated with a variant.
+    pub const fn expected(&self) -> &'static [&'static str] {
+        self.expected
+    }
+}
+
+impl core::fmt::Display for ParseAssociatedError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("expected one of: ")?;
+        for (i, expected) in self.expected.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "`{}`", expected)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseAssociatedError {}
+
 /// WIP: Cannot currently be derived.
 pub trait TryAssociated {
     type AssociatedType;
     fn try_get_associated(&self) -> Option<&'static Self::AssociatedType>;
+    /// Returns the constant associated with this variant, or `default` if there isn't one.
+    ///
+    /// ```rust
+    /// use associated::TryAssociated;
+    ///
+    /// enum Phonetic {
+    ///     Alpha,
+    ///     Unknown,
+    /// }
+    ///
+    /// impl TryAssociated for Phonetic {
+    ///     type AssociatedType = &'static str;
+    ///     fn try_get_associated(&self) -> Option<&'static Self::AssociatedType> {
+    ///         match self {
+    ///             Phonetic::Alpha => Some(&"Alpha"),
+    ///             Phonetic::Unknown => None,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Phonetic::Alpha.get_associated_or(&"?"), &"Alpha");
+    /// assert_eq!(Phonetic::Unknown.get_associated_or(&"?"), &"?");
+    /// ```
+    fn get_associated_or<'a>(&self, default: &'a Self::AssociatedType) -> &'a Self::AssociatedType
+    where
+        Self::AssociatedType: 'static,
+    {
+        self.try_get_associated().unwrap_or(default)
+    }
 }